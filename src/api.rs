@@ -0,0 +1,889 @@
+use super::fixtures;
+use super::monday;
+use super::monday::Client;
+use super::objects::*;
+use super::queries;
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+//Everything the TUI and CLI ask of monday.com. HttpApi sends real requests, or replays recorded
+//ones; FakeApi answers from an in-memory account, for tests and for trying the app without one.
+pub trait MondayApi: std::fmt::Debug {
+    //Boards
+    fn board_list(&self) -> Vec<Board>;
+    fn create_board(&self, board_name: String, kind: BoardKind, workspace_id: Option<i64>) -> String;
+    fn duplicate_board(
+        &self,
+        board_id: String,
+        duplicate: DuplicateBoardType,
+        board_name: Option<String>,
+        workspace_id: Option<i64>,
+        keep_subscribers: bool,
+    ) -> String;
+    fn archive_board(&self, board_id: String);
+    fn add_subscribers(&self, board_id: String, user_ids: Vec<i64>, owner: bool);
+    //Status labels of every status column
+    fn board_columns(&self, board_id: String) -> Vec<Label>;
+    fn column_schema(&self, board_id: String) -> Vec<Column>;
+    //Board subscribers
+    fn user_list(&self, board_id: String) -> Vec<User>;
+
+    //Groups
+    fn group_list(&self, board_id: String) -> Vec<Group>;
+    fn create_group(&self, board_id: String, group_name: String) -> String;
+    fn duplicate_group(&self, board_id: String, group_id: String, group_title: String) -> String;
+    fn archive_group(&self, board_id: String, group_id: String);
+    fn delete_group(&self, board_id: String, group_id: String);
+    fn rename_group(&self, board_id: String, group_id: String, title: String);
+    fn move_group(&self, board_id: String, group_id: String, neighbour_id: String, before: bool);
+
+    //Items
+    fn item_list(&self, board_id: String, group_id: String) -> Vec<Item>;
+    fn board_items(&self, board_id: String) -> Vec<Item>;
+    fn item_detail(&self, item_id: String) -> Item;
+    fn create_item(&self, board_id: String, group_id: String, item_name: String) -> Item;
+    fn move_item(&self, item_id: String, group_id: String);
+    fn create_update(&self, item_id: String, body: String) -> String;
+    fn change_status(&self, board_id: String, item_id: String, column_id: String, value: String);
+    fn assign_user(&self, board_id: String, item_id: String, column_id: String, value: String);
+    fn search_items_across_boards(&self, targets: &[queries::SearchTarget], query: &str) -> Result<Vec<Item>, String>;
+    //Items of the boards, and an error for each board that couldn't be loaded
+    fn items_across_boards(&self, board_ids: &[String]) -> (Vec<Item>, Vec<String>);
+    //Mutation fields sent together, with the outcome of each
+    fn batch_mutation(&self, fields: &[String]) -> Vec<Result<(), String>>;
+
+    //Account
+    fn current_user(&self) -> User;
+    fn account_users(&self) -> Vec<User>;
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpApi {
+    pub client: Client,
+}
+
+impl HttpApi {
+    //Talks to monday.com, recording or replaying fixtures as MONDAY_FIXTURES asks
+    pub fn new() -> Result<HttpApi, String> {
+        let client = match fixtures::from_env()? {
+            Some(fixtures::Mode::Replay(dir)) => monday::Client::replay(dir),
+            mode => monday::Client {
+                http: monday::get_client().map_err(|e| e.to_string())?,
+                fixtures: mode,
+            },
+        };
+        Ok(HttpApi { client })
+    }
+
+    //Answers every request from the fixtures under dir
+    pub fn replay(dir: PathBuf) -> HttpApi {
+        HttpApi { client: monday::Client::replay(dir) }
+    }
+}
+
+impl MondayApi for HttpApi {
+    fn board_list(&self) -> Vec<Board> {
+        queries::board_list(&self.client)
+    }
+
+    fn create_board(&self, board_name: String, kind: BoardKind, workspace_id: Option<i64>) -> String {
+        queries::create_board(&self.client, board_name, kind, workspace_id)
+    }
+
+    fn duplicate_board(
+        &self,
+        board_id: String,
+        duplicate: DuplicateBoardType,
+        board_name: Option<String>,
+        workspace_id: Option<i64>,
+        keep_subscribers: bool,
+    ) -> String {
+        queries::duplicate_board(&self.client, board_id, duplicate, board_name, workspace_id, keep_subscribers)
+    }
+
+    fn archive_board(&self, board_id: String) {
+        queries::archive_board(&self.client, board_id)
+    }
+
+    fn add_subscribers(&self, board_id: String, user_ids: Vec<i64>, owner: bool) {
+        queries::add_subscribers(&self.client, board_id, user_ids, owner)
+    }
+
+    fn board_columns(&self, board_id: String) -> Vec<Label> {
+        queries::board_columns(&self.client, board_id)
+    }
+
+    fn column_schema(&self, board_id: String) -> Vec<Column> {
+        queries::column_schema(&self.client, board_id)
+    }
+
+    fn user_list(&self, board_id: String) -> Vec<User> {
+        queries::user_list(&self.client, board_id)
+    }
+
+    fn group_list(&self, board_id: String) -> Vec<Group> {
+        queries::group_list(&self.client, board_id)
+    }
+
+    fn create_group(&self, board_id: String, group_name: String) -> String {
+        queries::create_group(&self.client, board_id, group_name)
+    }
+
+    fn duplicate_group(&self, board_id: String, group_id: String, group_title: String) -> String {
+        queries::duplicate_group(&self.client, board_id, group_id, group_title)
+    }
+
+    fn archive_group(&self, board_id: String, group_id: String) {
+        queries::archive_group(&self.client, board_id, group_id)
+    }
+
+    fn delete_group(&self, board_id: String, group_id: String) {
+        queries::delete_group(&self.client, board_id, group_id)
+    }
+
+    fn rename_group(&self, board_id: String, group_id: String, title: String) {
+        queries::rename_group(&self.client, board_id, group_id, title)
+    }
+
+    fn move_group(&self, board_id: String, group_id: String, neighbour_id: String, before: bool) {
+        queries::move_group(&self.client, board_id, group_id, neighbour_id, before)
+    }
+
+    fn item_list(&self, board_id: String, group_id: String) -> Vec<Item> {
+        queries::item_list(&self.client, board_id, group_id)
+    }
+
+    fn board_items(&self, board_id: String) -> Vec<Item> {
+        queries::board_items(&self.client, board_id)
+    }
+
+    fn item_detail(&self, item_id: String) -> Item {
+        queries::item_detail(&self.client, item_id)
+    }
+
+    fn create_item(&self, board_id: String, group_id: String, item_name: String) -> Item {
+        queries::create_item(&self.client, board_id, group_id, item_name)
+    }
+
+    fn move_item(&self, item_id: String, group_id: String) {
+        queries::move_item(&self.client, item_id, group_id)
+    }
+
+    fn create_update(&self, item_id: String, body: String) -> String {
+        queries::create_update(&self.client, item_id, body)
+    }
+
+    fn change_status(&self, board_id: String, item_id: String, column_id: String, value: String) {
+        queries::change_status(&self.client, board_id, item_id, column_id, value)
+    }
+
+    fn assign_user(&self, board_id: String, item_id: String, column_id: String, value: String) {
+        queries::assign_user(&self.client, board_id, item_id, column_id, value)
+    }
+
+    fn search_items_across_boards(&self, targets: &[queries::SearchTarget], query: &str) -> Result<Vec<Item>, String> {
+        queries::search_items_across_boards(&self.client, targets, query)
+    }
+
+    fn items_across_boards(&self, board_ids: &[String]) -> (Vec<Item>, Vec<String>) {
+        queries::items_across_boards(&self.client, board_ids)
+    }
+
+    fn batch_mutation(&self, fields: &[String]) -> Vec<Result<(), String>> {
+        queries::batch_mutation(&self.client, fields)
+    }
+
+    fn current_user(&self) -> User {
+        queries::current_user(&self.client)
+    }
+
+    fn account_users(&self) -> Vec<User> {
+        queries::account_users(&self.client)
+    }
+}
+
+//Timestamp given to everything the fake account changes, so runs are repeatable
+const FAKE_NOW: &str = "2021-07-01T12:00:00Z";
+
+//A board of the fake account with its groups, columns and subscribers
+#[derive(Debug, Clone)]
+pub struct FakeBoard {
+    pub board: Board,
+    pub groups: Vec<Group>,
+    pub columns: Vec<Column>,
+    //Status labels by column id
+    pub labels: HashMap<String, Vec<String>>,
+    pub subscribers: Vec<User>,
+    //Workspace it was created in, None for the main one
+    pub workspace_id: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeAccount {
+    pub boards: Vec<FakeBoard>,
+    pub items: Vec<Item>,
+    pub users: Vec<User>,
+    pub current_user: User,
+    //Batched mutation fields received, in order
+    pub mutations: Vec<String>,
+    pub next_id: u64,
+}
+
+impl Default for FakeAccount {
+    fn default() -> FakeAccount {
+        FakeAccount::new()
+    }
+}
+
+impl FakeAccount {
+    pub fn new() -> FakeAccount {
+        FakeAccount {
+            boards: Vec::new(),
+            items: Vec::new(),
+            users: Vec::new(),
+            current_user: User::new(),
+            mutations: Vec::new(),
+            next_id: 5000,
+        }
+    }
+
+    //A small account to click around in
+    pub fn demo() -> FakeAccount {
+        let mut account = FakeAccount::new();
+        account.add_user(1, "Ada Lovelace", "ada@example.com");
+        account.add_user(2, "Grace Hopper", "grace@example.com");
+        account.add_user(3, "Linus Torvalds", "linus@example.com");
+        account.current_user = account.users[0].clone();
+
+        account.add_board("1001", "Product Roadmap");
+        account.add_group("1001", "sprint", "This Sprint");
+        account.add_group("1001", "backlog", "Backlog");
+        account.add_group("1001", "shipped", "Shipped");
+        account.add_column("1001", "status", "Status", "color", &["Working on it", "Stuck", "Done"]);
+        account.add_column("1001", "person", "Owner", "multiple-person", &[]);
+        account.add_column("1001", "date", "Due", "date", &[]);
+        for (group, name, status, owner, due) in &[
+            ("sprint", "Fix login redirect", "Working on it", "Ada Lovelace", "2021-07-05"),
+            ("sprint", "Dark mode", "Stuck", "Grace Hopper", "2021-07-09"),
+            ("sprint", "Export to CSV", "", "Ada Lovelace", "2021-07-12"),
+            ("backlog", "Keyboard shortcuts", "", "Linus Torvalds", ""),
+            ("backlog", "Offline mode", "", "", ""),
+            ("shipped", "Release 1.0", "Done", "Grace Hopper", "2021-06-30"),
+        ] {
+            let id = account.add_item("1001", group, name);
+            account.set_column(&id, "status", status);
+            account.set_column(&id, "person", owner);
+            account.set_column(&id, "date", due);
+        }
+
+        account.add_board("1002", "Hiring");
+        account.add_group("1002", "applied", "Applied");
+        account.add_group("1002", "interviewing", "Interviewing");
+        account.add_column("1002", "status", "Stage", "color", &["Phone Screen", "Onsite", "Offer"]);
+        account.add_column("1002", "person", "Interviewer", "multiple-person", &[]);
+        for (group, name, status, owner) in &[
+            ("applied", "Backend Engineer - J. Doe", "", ""),
+            ("interviewing", "Designer - A. Smith", "Onsite", "Ada Lovelace"),
+        ] {
+            let id = account.add_item("1002", group, name);
+            account.set_column(&id, "status", status);
+            account.set_column(&id, "person", owner);
+        }
+
+        for board_id in &["1001", "1002"] {
+            for user_id in 1..=3 {
+                account.subscribe(board_id, user_id);
+            }
+        }
+        account
+    }
+
+    pub fn add_user(&mut self, id: i64, name: &str, email: &str) {
+        self.users.push(User {
+            id,
+            name: name.to_string(),
+            email: email.to_string(),
+        });
+    }
+
+    pub fn add_board(&mut self, id: &str, name: &str) {
+        self.boards.push(FakeBoard {
+            board: Board {
+                id: id.to_string(),
+                name: name.to_string(),
+            },
+            groups: Vec::new(),
+            columns: Vec::new(),
+            labels: HashMap::new(),
+            subscribers: Vec::new(),
+            workspace_id: None,
+        });
+    }
+
+    pub fn add_group(&mut self, board_id: &str, id: &str, title: &str) {
+        if let Some(board) = self.board_mut(board_id) {
+            board.groups.push(Group {
+                id: id.to_string(),
+                title: title.to_string(),
+                color: String::new(),
+            });
+        }
+    }
+
+    //Columns are added before items; items get a value for every column of their board
+    pub fn add_column(&mut self, board_id: &str, id: &str, title: &str, type_: &str, labels: &[&str]) {
+        if let Some(board) = self.board_mut(board_id) {
+            board.columns.push(Column {
+                id: id.to_string(),
+                title: title.to_string(),
+                type_: type_.to_string(),
+            });
+            if !labels.is_empty() {
+                board
+                    .labels
+                    .insert(id.to_string(), labels.iter().map(|l| l.to_string()).collect());
+            }
+        }
+    }
+
+    pub fn subscribe(&mut self, board_id: &str, user_id: i64) {
+        let user = match self.users.iter().find(|user| user.id == user_id) {
+            Some(user) => user.clone(),
+            None => return,
+        };
+        if let Some(board) = self.board_mut(board_id) {
+            if !board.subscribers.iter().any(|sub| sub.id == user_id) {
+                board.subscribers.push(user);
+            }
+        }
+    }
+
+    //Adds an item and returns its id
+    pub fn add_item(&mut self, board_id: &str, group_id: &str, name: &str) -> String {
+        let id = self.new_id();
+        let board = match self.board(board_id) {
+            Some(board) => board.clone(),
+            None => return id,
+        };
+        let mut item = Item::new();
+        item.id = id.clone();
+        item.name = name.to_string();
+        item.board = board.board.clone();
+        item.group = board
+            .groups
+            .iter()
+            .find(|group| group.id == group_id)
+            .cloned()
+            .unwrap_or_else(Group::new);
+        item.creator = self.current_user.clone();
+        item.subscribers = vec![self.current_user.clone()];
+        item.updated_at = FAKE_NOW.to_string();
+        item.column_values = board
+            .columns
+            .iter()
+            .map(|column| ColumnValue {
+                additional_info: String::new(),
+                id: column.id.clone(),
+                text: String::new(),
+                title: column.title.clone(),
+                type_: column.type_.clone(),
+            })
+            .collect();
+        self.items.push(item);
+        id
+    }
+
+    pub fn set_column(&mut self, item_id: &str, column_id: &str, text: &str) {
+        if let Some(item) = self.items.iter_mut().find(|item| item.id == item_id) {
+            if let Some(value) = item.column_values.iter_mut().find(|value| value.id == column_id) {
+                value.text = text.to_string();
+            }
+            item.updated_at = FAKE_NOW.to_string();
+        }
+    }
+
+    pub fn board(&self, board_id: &str) -> Option<&FakeBoard> {
+        self.boards.iter().find(|board| board.board.id == board_id)
+    }
+
+    pub fn board_mut(&mut self, board_id: &str) -> Option<&mut FakeBoard> {
+        self.boards.iter_mut().find(|board| board.board.id == board_id)
+    }
+
+    pub fn item(&self, item_id: &str) -> Option<&Item> {
+        self.items.iter().find(|item| item.id == item_id)
+    }
+
+    fn new_id(&mut self) -> String {
+        self.next_id += 1;
+        self.next_id.to_string()
+    }
+
+    fn move_item(&mut self, item_id: &str, group_id: &str) {
+        let group = self
+            .items
+            .iter()
+            .find(|item| item.id == item_id)
+            .and_then(|item| self.board(&item.board.id))
+            .and_then(|board| board.groups.iter().find(|group| group.id == group_id))
+            .cloned();
+        if let (Some(group), Some(item)) = (group, self.items.iter_mut().find(|item| item.id == item_id)) {
+            item.group = group;
+            item.updated_at = FAKE_NOW.to_string();
+        }
+    }
+
+    fn add_update(&mut self, item_id: &str, body: &str) -> String {
+        let id = self.new_id();
+        let creator = self.current_user.clone();
+        if let Some(item) = self.items.iter_mut().find(|item| item.id == item_id) {
+            item.updates.insert(
+                0,
+                Update {
+                    creator,
+                    replies: Vec::new(),
+                    text_body: body.to_string(),
+                    updated_at: FAKE_NOW.to_string(),
+                },
+            );
+        }
+        id
+    }
+
+    //Applies a change_column_value value the way monday renders it as text
+    fn change_column(&mut self, item_id: &str, column_id: &str, value: &Value) {
+        let text = if let Some(label) = value.get("label").and_then(|l| l.as_str()) {
+            label.to_string()
+        } else if let Some(people) = value.get("personsAndTeams").and_then(|p| p.as_array()) {
+            people
+                .iter()
+                .filter_map(|person| person.get("id").and_then(|id| id.as_i64()))
+                .filter_map(|id| self.users.iter().find(|user| user.id == id))
+                .map(|user| user.name.clone())
+                .collect::<Vec<String>>()
+                .join(", ")
+        } else {
+            value.as_str().unwrap_or_default().to_string()
+        };
+        self.set_column(item_id, column_id, &text);
+    }
+
+    //Applies one field of a batched mutation, as built by the bulk actions
+    fn apply_mutation(&mut self, field: &str) -> Result<(), String> {
+        let (name, args) = parse_mutation(field)?;
+        let arg = |key: &str| {
+            args.get(key)
+                .map(|v| v.as_str().map(|s| s.to_string()).unwrap_or_else(|| v.to_string()))
+                .ok_or_else(|| format!("missing argument {}", key))
+        };
+        let item_id = arg("item_id")?;
+        if self.item(&item_id).is_none() {
+            return Err(format!("Item {} not found", item_id));
+        }
+        match name.as_str() {
+            "change_column_value" => {
+                let value = serde_json::from_str::<Value>(&arg("value")?).map_err(|e| e.to_string())?;
+                self.change_column(&item_id, &arg("column_id")?, &value);
+            }
+            "move_item_to_group" => self.move_item(&item_id, &arg("group_id")?),
+            "archive_item" => self.items.retain(|item| item.id != item_id),
+            "create_update" => {
+                self.add_update(&item_id, &arg("body")?);
+            }
+            _ => return Err(format!("unsupported mutation {}", name)),
+        }
+        Ok(())
+    }
+}
+
+//Name and arguments of a mutation field like `archive_item(item_id: 1) { id }`
+fn parse_mutation(field: &str) -> Result<(String, HashMap<String, Value>), String> {
+    let open = field.find('(').ok_or_else(|| format!("invalid mutation: {}", field))?;
+    let name = field[..open].trim().to_string();
+    let mut args = HashMap::new();
+    let mut rest = &field[open + 1..];
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        if rest.starts_with(')') || rest.is_empty() {
+            break;
+        }
+        let colon = rest.find(':').ok_or_else(|| format!("invalid mutation: {}", field))?;
+        let key = rest[..colon].trim().to_string();
+        rest = rest[colon + 1..].trim_start();
+        //Values are JSON literals: quoted strings or numbers
+        let mut values = serde_json::Deserializer::from_str(rest).into_iter::<Value>();
+        let value = match values.next() {
+            Some(Ok(value)) => value,
+            _ => return Err(format!("invalid mutation: {}", field)),
+        };
+        rest = &rest[values.byte_offset()..];
+        args.insert(key, value);
+    }
+    Ok((name, args))
+}
+
+#[derive(Debug)]
+pub struct FakeApi {
+    pub account: RefCell<FakeAccount>,
+}
+
+impl FakeApi {
+    pub fn new(account: FakeAccount) -> FakeApi {
+        FakeApi {
+            account: RefCell::new(account),
+        }
+    }
+
+    pub fn demo() -> FakeApi {
+        FakeApi::new(FakeAccount::demo())
+    }
+}
+
+impl MondayApi for FakeApi {
+    fn board_list(&self) -> Vec<Board> {
+        self.account.borrow().boards.iter().map(|board| board.board.clone()).collect()
+    }
+
+    fn create_board(&self, board_name: String, _kind: BoardKind, workspace_id: Option<i64>) -> String {
+        let mut account = self.account.borrow_mut();
+        let id = account.new_id();
+        account.add_board(&id, &board_name);
+        if let Some(board) = account.board_mut(&id) {
+            board.workspace_id = workspace_id;
+        }
+        account.add_group(&id, "topics", "Group Title");
+        let user_id = account.current_user.id;
+        account.subscribe(&id, user_id);
+        id
+    }
+
+    fn duplicate_board(
+        &self,
+        board_id: String,
+        duplicate: DuplicateBoardType,
+        board_name: Option<String>,
+        _workspace_id: Option<i64>,
+        keep_subscribers: bool,
+    ) -> String {
+        let mut account = self.account.borrow_mut();
+        let mut board = match account.board(&board_id) {
+            Some(board) => board.clone(),
+            None => return String::new(),
+        };
+        let id = account.new_id();
+        board.board = Board {
+            id: id.clone(),
+            name: board_name.unwrap_or_else(|| format!("Duplicate of {}", board.board.name)),
+        };
+        if !keep_subscribers {
+            board.subscribers = vec![account.current_user.clone()];
+        }
+        account.boards.push(board.clone());
+        if duplicate != DuplicateBoardType::Structure {
+            let items = account
+                .items
+                .iter()
+                .filter(|item| item.board.id == board_id)
+                .cloned()
+                .collect::<Vec<Item>>();
+            for mut item in items {
+                item.id = account.new_id();
+                item.board = board.board.clone();
+                if duplicate == DuplicateBoardType::Pulses {
+                    item.updates = Vec::new();
+                }
+                account.items.push(item);
+            }
+        }
+        id
+    }
+
+    fn archive_board(&self, board_id: String) {
+        let mut account = self.account.borrow_mut();
+        account.boards.retain(|board| board.board.id != board_id);
+        account.items.retain(|item| item.board.id != board_id);
+    }
+
+    fn add_subscribers(&self, board_id: String, user_ids: Vec<i64>, _owner: bool) {
+        let mut account = self.account.borrow_mut();
+        for user_id in user_ids {
+            account.subscribe(&board_id, user_id);
+        }
+    }
+
+    //Label names come back JSON quoted, as they do from the API
+    fn board_columns(&self, board_id: String) -> Vec<Label> {
+        let account = self.account.borrow();
+        let board = match account.board(&board_id) {
+            Some(board) => board,
+            None => return Vec::new(),
+        };
+        board
+            .columns
+            .iter()
+            .filter(|column| column.is_status())
+            .flat_map(|column| {
+                board.labels.get(&column.id).into_iter().flatten().map(move |label| Label {
+                    column_id: column.id.clone(),
+                    column_title: column.title.clone(),
+                    name: Value::from(label.as_str()).to_string(),
+                })
+            })
+            .collect()
+    }
+
+    fn column_schema(&self, board_id: String) -> Vec<Column> {
+        self.account.borrow().board(&board_id).map(|board| board.columns.clone()).unwrap_or_default()
+    }
+
+    fn user_list(&self, board_id: String) -> Vec<User> {
+        self.account.borrow().board(&board_id).map(|board| board.subscribers.clone()).unwrap_or_default()
+    }
+
+    fn group_list(&self, board_id: String) -> Vec<Group> {
+        self.account.borrow().board(&board_id).map(|board| board.groups.clone()).unwrap_or_default()
+    }
+
+    //New groups go on top, as on monday.com
+    fn create_group(&self, board_id: String, group_name: String) -> String {
+        let mut account = self.account.borrow_mut();
+        let id = format!("group_{}", account.new_id());
+        if let Some(board) = account.board_mut(&board_id) {
+            board.groups.insert(
+                0,
+                Group {
+                    id: id.clone(),
+                    title: group_name,
+                    color: String::new(),
+                },
+            );
+        }
+        id
+    }
+
+    fn duplicate_group(&self, board_id: String, group_id: String, group_title: String) -> String {
+        let mut account = self.account.borrow_mut();
+        let id = format!("group_{}", account.new_id());
+        let group = Group {
+            id: id.clone(),
+            title: group_title,
+            color: String::new(),
+        };
+        match account.board_mut(&board_id) {
+            Some(board) => {
+                let position = board.groups.iter().position(|g| g.id == group_id).map(|i| i + 1).unwrap_or(0);
+                board.groups.insert(position, group.clone());
+            }
+            None => return id,
+        }
+        let items = account
+            .items
+            .iter()
+            .filter(|item| item.board.id == board_id && item.group.id == group_id)
+            .cloned()
+            .collect::<Vec<Item>>();
+        for mut item in items {
+            item.id = account.new_id();
+            item.group = group.clone();
+            account.items.push(item);
+        }
+        id
+    }
+
+    fn archive_group(&self, board_id: String, group_id: String) {
+        self.delete_group(board_id, group_id)
+    }
+
+    fn delete_group(&self, board_id: String, group_id: String) {
+        let mut account = self.account.borrow_mut();
+        if let Some(board) = account.board_mut(&board_id) {
+            board.groups.retain(|group| group.id != group_id);
+        }
+        account
+            .items
+            .retain(|item| !(item.board.id == board_id && item.group.id == group_id));
+    }
+
+    fn rename_group(&self, board_id: String, group_id: String, title: String) {
+        let mut account = self.account.borrow_mut();
+        if let Some(group) = account
+            .board_mut(&board_id)
+            .and_then(|board| board.groups.iter_mut().find(|group| group.id == group_id))
+        {
+            group.title = title.clone();
+        }
+        for item in account.items.iter_mut().filter(|item| item.group.id == group_id) {
+            item.group.title = title.clone();
+        }
+    }
+
+    fn move_group(&self, board_id: String, group_id: String, neighbour_id: String, before: bool) {
+        let mut account = self.account.borrow_mut();
+        let board = match account.board_mut(&board_id) {
+            Some(board) => board,
+            None => return,
+        };
+        let group = match board.groups.iter().position(|group| group.id == group_id) {
+            Some(i) => board.groups.remove(i),
+            None => return,
+        };
+        let position = match board.groups.iter().position(|group| group.id == neighbour_id) {
+            Some(i) if before => i,
+            Some(i) => i + 1,
+            None => board.groups.len(),
+        };
+        board.groups.insert(position, group);
+    }
+
+    fn item_list(&self, board_id: String, group_id: String) -> Vec<Item> {
+        self.account
+            .borrow()
+            .items
+            .iter()
+            .filter(|item| item.board.id == board_id && item.group.id == group_id)
+            .cloned()
+            .collect()
+    }
+
+    fn board_items(&self, board_id: String) -> Vec<Item> {
+        self.account
+            .borrow()
+            .items
+            .iter()
+            .filter(|item| item.board.id == board_id)
+            .cloned()
+            .collect()
+    }
+
+    fn item_detail(&self, item_id: String) -> Item {
+        self.account.borrow().item(&item_id).cloned().unwrap_or_else(Item::new)
+    }
+
+    fn create_item(&self, board_id: String, group_id: String, item_name: String) -> Item {
+        let mut account = self.account.borrow_mut();
+        let id = account.add_item(&board_id, &group_id, &item_name);
+        let mut item = Item::new();
+        item.id = id;
+        item
+    }
+
+    fn move_item(&self, item_id: String, group_id: String) {
+        self.account.borrow_mut().move_item(&item_id, &group_id)
+    }
+
+    fn create_update(&self, item_id: String, body: String) -> String {
+        self.account.borrow_mut().add_update(&item_id, &body)
+    }
+
+    fn change_status(&self, _board_id: String, item_id: String, column_id: String, value: String) {
+        let label = Value::from(value.replace("\"", ""));
+        let mut account = self.account.borrow_mut();
+        account.change_column(&item_id, &column_id, &serde_json::json!({ "label": label }));
+    }
+
+    fn assign_user(&self, _board_id: String, item_id: String, column_id: String, value: String) {
+        let user_id = value.replace("\"", "").parse::<i64>().unwrap_or_default();
+        let mut account = self.account.borrow_mut();
+        let people = serde_json::json!({ "personsAndTeams": [{ "id": user_id, "kind": "person" }] });
+        account.change_column(&item_id, &column_id, &people);
+    }
+
+    fn search_items_across_boards(&self, targets: &[queries::SearchTarget], query: &str) -> Result<Vec<Item>, String> {
+        let account = self.account.borrow();
+        Ok(account
+            .items
+            .iter()
+            .filter(|item| {
+                targets.iter().any(|target| {
+                    target.board_id == item.board.id
+                        && (item.name == query
+                            || item
+                                .column_values
+                                .iter()
+                                .any(|value| target.column_ids.contains(&value.id) && value.text == query))
+                })
+            })
+            .cloned()
+            .collect())
+    }
+
+    fn items_across_boards(&self, board_ids: &[String]) -> (Vec<Item>, Vec<String>) {
+        let account = self.account.borrow();
+        let items = account
+            .items
+            .iter()
+            .filter(|item| board_ids.contains(&item.board.id))
+            .cloned()
+            .collect();
+        (items, Vec::new())
+    }
+
+    fn batch_mutation(&self, fields: &[String]) -> Vec<Result<(), String>> {
+        let mut account = self.account.borrow_mut();
+        account.mutations.extend(fields.iter().cloned());
+        fields.iter().map(|field| account.apply_mutation(field)).collect()
+    }
+
+    fn current_user(&self) -> User {
+        self.account.borrow().current_user.clone()
+    }
+
+    fn account_users(&self) -> Vec<User> {
+        self.account.borrow().users.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fake_applies_batched_mutations() {
+        let api = FakeApi::demo();
+        let items = api.item_list(String::from("1001"), String::from("sprint"));
+        let fields = vec![
+            format!(
+                "change_column_value(item_id: {}, board_id: 1001, column_id: \"status\", value: {}) {{ id }}",
+                items[0].id,
+                serde_json::to_string("{\"label\":\"Done\"}").unwrap()
+            ),
+            format!("move_item_to_group(item_id: {}, group_id: \"backlog\") {{ id }}", items[1].id),
+            String::from("archive_item(item_id: 1) { id }"),
+        ];
+        let results = api.batch_mutation(&fields);
+        assert!(results[0].is_ok() && results[1].is_ok());
+        assert!(results[2].is_err());
+
+        let first = api.item_detail(items[0].id.clone());
+        let status = first.column_values.iter().find(|value| value.id == "status").unwrap();
+        assert_eq!(status.text, "Done");
+        assert_eq!(api.item_detail(items[1].id.clone()).group.id, "backlog");
+        assert_eq!(api.account.borrow().mutations.len(), 3);
+    }
+
+    #[test]
+    fn fake_groups_and_items() {
+        let api = FakeApi::demo();
+        let board_id = String::from("1001");
+        let group_id = api.create_group(board_id.clone(), String::from("Ideas"));
+        assert_eq!(api.group_list(board_id.clone())[0].title, "Ideas");
+
+        let item = api.create_item(board_id.clone(), group_id.clone(), String::from("Plugin system"));
+        api.assign_user(board_id.clone(), item.id.clone(), String::from("person"), String::from("2"));
+        api.change_status(board_id.clone(), item.id.clone(), String::from("status"), String::from("\"Stuck\""));
+        let detail = api.item_detail(item.id.clone());
+        assert_eq!(detail.name, "Plugin system");
+        assert_eq!(detail.group.title, "Ideas");
+        let text = |id: &str| detail.column_values.iter().find(|value| value.id == id).unwrap().text.clone();
+        assert_eq!(text("person"), "Grace Hopper");
+        assert_eq!(text("status"), "Stuck");
+
+        api.move_group(board_id.clone(), group_id.clone(), String::from("shipped"), false);
+        let groups = api.group_list(board_id.clone());
+        assert_eq!(groups.last().unwrap().id, group_id);
+        api.delete_group(board_id.clone(), group_id);
+        assert!(api.board_items(board_id).iter().all(|item| item.name != "Plugin system"));
+    }
+}
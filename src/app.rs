@@ -1,22 +1,42 @@
 use crossterm::{
+    cursor,
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen},
-    event::{KeyCode}, 
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use reqwest;
+use std::collections::HashMap;
 use std::io;
+use std::ops::{Deref, DerefMut};
+use std::panic;
+use std::rc::Rc;
 use tui::{backend::CrosstermBackend, widgets::ListState, Terminal};
 use serde::{Serialize, Deserialize}; 
 
-use super::monday;
+use super::api;
+use super::bulk;
+use super::filter;
+use super::history;
+use super::index;
+use super::keymap;
+use super::mouse;
 use super::objects;
-use super::queries;
 use super::views;
 use super::cache; 
 
+//Where letters go: key bindings in Normal, the view's search box in Search and its text field in Insert
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum InputMode {
+    Normal,
+    Search,
+    Insert,
+}
+
 #[derive(Debug, Clone)]
 pub struct App {
+    //Selection of the list being shown. It's swapped out for the list's own saved state when the view changes.
     pub list_state: ListState,
+    pub list_states : HashMap<String, ListState>, 
+    pub list_key : String, 
     pub boards: Vec<objects::Board>,
     pub board_detail : objects::Board, 
     pub groups : Vec<objects::Group>, 
@@ -25,13 +45,56 @@ pub struct App {
     pub item_detail: objects::Item,
     pub active_menu_item: views::MenuItem,
     pub key_input: Vec<char>,
-    pub client: reqwest::blocking::Client,
+    pub api: Rc<dyn api::MondayApi>,
     pub menu_titles: Vec<String>,
     pub status_labels: Vec<objects::Label>, 
     pub users : Vec<objects::User>, 
     pub cache : cache::Cache, 
-    pub f : KeyCode, 
+    pub filter : filter::FilterState, 
     pub current_user : objects::User, 
+    pub board_kind : objects::BoardKind, 
+    //Workspace id typed for a new board, swapped with key_input while it's being edited
+    pub board_workspace : Vec<char>, 
+    pub editing_workspace : bool, 
+    pub duplicate_type : objects::DuplicateBoardType, 
+    pub selected_items : Vec<String>, 
+    pub bulk : bool, 
+    pub bulk_job : Option<bulk::BulkJob>, 
+    pub table_items : Vec<objects::Item>, 
+    //Every item on the board, for the group filter chip which reaches past the open group
+    pub board_items : Vec<objects::Item>, 
+    pub table_board_scope : bool, 
+    pub table_column_offset : usize, 
+    //Item list search the table was opened with
+    pub table_query : Vec<char>, 
+    pub view_query : Vec<char>, 
+    pub find_results : Vec<objects::Item>, 
+    pub find_query : String, 
+    pub find_error : Option<String>, 
+    pub index : index::SearchIndex, 
+    //Where the cache and index are saved
+    pub dir : String, 
+    pub dashboard : Option<Vec<objects::Item>>, 
+    pub dashboard_error : Option<String>, 
+    pub dashboard_by_due : bool, 
+    pub status_column : String, 
+    pub board_columns : Vec<objects::Column>, 
+    pub switch_return : views::MenuItem, 
+    pub keymap : keymap::Keymap, 
+    //Key bindings overlay is open
+    pub show_keymap : bool, 
+    pub input_mode : InputMode, 
+    //View the input mode was last reset for
+    pub input_view : String, 
+    pub history : history::History, 
+    //Help bar entries of the active view
+    pub help : Vec<(String, String)>, 
+    //What the last frame drew that the mouse can click
+    pub mouse : mouse::Targets, 
+    //Lines item detail is scrolled down by
+    pub detail_scroll : usize, 
+    //Option waiting for a second Enter before it destroys something, by view and row
+    pub confirming : Option<(views::MenuItem, usize)>, 
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +111,9 @@ pub struct AppState {
     pub status_labels: Vec<objects::Label>, 
     pub users : Vec<objects::User>, 
     pub current_user : objects::User, 
+    //Selected row of each list, by list key. Scroll offsets are recomputed from the selection.
+    #[serde(default)]
+    pub selections : HashMap<String, usize>, 
 }
 
 impl From<App> for AppState {
@@ -64,18 +130,39 @@ impl From<App> for AppState {
             menu_titles: app.menu_titles.clone(),
             status_labels: app.status_labels.clone(), 
             users : app.users.clone(), 
-            current_user : app.current_user.clone()
+            current_user : app.current_user.clone(), 
+            selections : app
+                .list_states
+                .iter()
+                .filter_map(|(key, state)| state.selected().map(|selected| (key.clone(), selected)))
+                .collect(), 
         }
     }
 }
 
 impl From<AppState> for App {
     fn from(app_state: AppState) -> App {
-        let cache = cache::Cache::new(); 
+        let api: Rc<dyn api::MondayApi> = Rc::new(api::HttpApi::new().expect("Could not get client."));
+        App::from_parts(app_state, cache::Cache::new(&*api), api, index::read(), cache::get_cache_dir())
+    }
+}
+
+impl App {
+    pub fn new(api: Rc<dyn api::MondayApi>) -> App {
+        let cache = cache::Cache::new(&*api); 
+        App::from_parts(cache.app_state.clone(), cache, api, index::read(), cache::get_cache_dir())
+    }
+
+    //App over a given cache, API and index saving to dir, e.g. in tests where nothing should be read from disk
+    pub fn from_parts(
+        app_state: AppState,
+        cache: cache::Cache,
+        api: Rc<dyn api::MondayApi>,
+        index: index::SearchIndex,
+        cache_dir: String,
+    ) -> App {
         let mut list_state = ListState::default();
         list_state.select(Some(0));
-        let client = monday::get_client().expect("Could not get client.");
-        let f = KeyCode::Null; 
 
         let app = App {
             boards : app_state.boards.clone(),
@@ -90,28 +177,163 @@ impl From<AppState> for App {
             status_labels : app_state.status_labels.clone(), 
             users : app_state.users.clone(),
             current_user : app_state.current_user.clone(),
-            f : f, 
-            client : client, 
-            list_state : list_state, 
-            cache : cache
+            filter : filter::FilterState::default(), 
+            board_kind : objects::BoardKind::Public, 
+            board_workspace : Vec::new(), 
+            editing_workspace : false, 
+            duplicate_type : objects::DuplicateBoardType::Structure, 
+            selected_items : Vec::new(), 
+            bulk : false, 
+            bulk_job : None, 
+            table_items : Vec::new(), 
+            board_items : Vec::new(), 
+            table_board_scope : false, 
+            table_column_offset : 0, 
+            table_query : Vec::new(), 
+            view_query : Vec::new(), 
+            find_results : Vec::new(), 
+            find_query : String::new(), 
+            find_error : None, 
+            index, 
+            dir : cache_dir, 
+            dashboard : None, 
+            dashboard_error : None, 
+            dashboard_by_due : false, 
+            status_column : String::new(), 
+            board_columns : Vec::new(), 
+            switch_return : views::MenuItem::Home,
+            keymap : keymap::Keymap::default(), 
+            show_keymap : false, 
+            input_mode : InputMode::Normal, 
+            input_view : String::new(), 
+            history : history::History::default(), 
+            help : Vec::new(), 
+            mouse : mouse::Targets::default(), 
+            detail_scroll : 0, 
+            confirming : None, 
+            api, 
+            list_state, 
+            list_states : app_state
+                .selections
+                .iter()
+                .map(|(key, selected)| {
+                    let mut state = ListState::default();
+                    state.select(Some(*selected));
+                    (key.clone(), state)
+                })
+                .collect(), 
+            list_key : String::new(), 
+            cache
         };
         return app; 
     }
-}
 
-impl App {
-    pub fn new() -> App {
-        let cache = cache::Cache::new(); 
-        App::from(cache.app_state)
+    //Each view starts in its keymap's mode; a search started from normal mode ends when the view changes
+    pub fn sync_input_mode(&mut self) {
+        let view = format!("{:?}", self.active_menu_item);
+        if self.input_view != view {
+            self.input_mode = self.keymap.base_mode(&view);
+            self.input_view = view;
+        }
+    }
+
+    //Brings the input mode, list selection and history up to date before a frame is drawn
+    pub fn sync_view(&mut self) {
+        self.sync_input_mode();
+        self.sync_list_state();
+        history::track(self);
+        //A pending confirmation is dropped once the user moves off it
+        if self.confirming != Some(self.selected_option()) {
+            self.confirming = None;
+        }
+    }
+
+    fn selected_option(&self) -> (views::MenuItem, usize) {
+        (self.active_menu_item, self.list_state.selected().unwrap_or(0))
+    }
+
+    //True on the second Enter on the same option. The first only asks for confirmation.
+    pub fn confirm(&mut self) -> bool {
+        if self.confirming == Some(self.selected_option()) {
+            self.confirming = None;
+            return true;
+        }
+        self.confirming = Some(self.selected_option());
+        false
+    }
+
+    //Lists of different boards and groups keep separate selections
+    pub fn current_list_key(&self) -> String {
+        match self.active_menu_item {
+            views::MenuItem::Groups => format!("Groups:{}", self.board_detail.id),
+            views::MenuItem::Items => format!("Items:{}:{}", self.board_detail.id, self.group_detail.id),
+            menu_item => format!("{:?}", menu_item),
+        }
+    }
+
+    //Saves the shown list's selection, or brings back the saved one when another list is shown.
+    //A list seen for the first time keeps the selection the view opened it with.
+    pub fn sync_list_state(&mut self) {
+        let key = self.current_list_key();
+        if self.list_key != key {
+            if let Some(saved) = self.list_states.get(&key) {
+                self.list_state = saved.clone();
+                self.mouse.list_offset = self.mouse.list_offsets.get(&key).copied().unwrap_or(0);
+            }
+            self.list_key = key.clone();
+        }
+        self.mouse.list_offsets.insert(key.clone(), self.mouse.list_offset);
+        self.list_states.insert(key, self.list_state.clone());
+    }
+
+    //Switches the current board. Filter chips belong to a board, so they're cleared when it changes.
+    pub fn set_board(&mut self, board: objects::Board) {
+        if board.id != self.board_detail.id {
+            self.filter = filter::FilterState::default();
+            self.board_items = Vec::new();
+        }
+        self.board_detail = board;
+    }
+
+    //Letters are typed rather than looked up in the keymap
+    pub fn is_typing(&self) -> bool {
+        self.input_mode != InputMode::Normal
+    }
+
+    //Fetches a group's items and replaces the group's entries in the local search index
+    pub fn fetch_items(&mut self, board_id: String, group_id: String) {
+        self.items = self.api.item_list(board_id, group_id.clone());
+        self.index.replace_items(&self.items, &self.board_detail, Some(&group_id));
+    }
+
+    //Loads the whole board's items while the group filter is set, as it can pick groups other than the open one
+    pub fn load_board_items(&mut self) {
+        if self.filter.groups.is_empty() {
+            return;
+        }
+        self.board_items = self.api.board_items(self.board_detail.id.clone());
+        self.index.replace_items(&self.board_items, &self.board_detail, None);
+    }
+
+    //Saves the cache, and the search index with it so it isn't lost when the app doesn't quit cleanly
+    pub fn write_cache(&self) {
+        cache::write(&self.dir, &self.cache).expect("could not write cache");
+        index::write(&self.dir, &self.index).expect("could not write search index");
+    }
+
+    //Fetches an item with its updates and refreshes it in the local search index
+    pub fn fetch_item_detail(&mut self, item_id: String) {
+        self.item_detail = self.api.item_detail(item_id);
+        self.detail_scroll = 0;
+        self.index.add_item(&self.item_detail, &self.board_detail);
     }
 }
 
 impl AppState {
-    pub fn new() -> AppState {
+    pub fn new(api: &dyn api::MondayApi) -> AppState {
         let active_menu_item = views::MenuItem::Home;
         let key_input: Vec<char> = Vec::new();
-        let client = monday::get_client().expect("Could not get client.");
-        let boards: Vec<objects::Board> = queries::board_list(&client);
+        let boards: Vec<objects::Board> = api.board_list();
         let groups : Vec<objects::Group> = Vec::new(); 
         let items: Vec<objects::Item> = Vec::new();
  
@@ -124,29 +346,64 @@ impl AppState {
             item_detail: objects::Item::new(),
             active_menu_item: active_menu_item,
             key_input: key_input,
-            menu_titles: vec!["Home", "Boards", "Groups", "Items", "Item Detail"]
+            menu_titles: ["Home", "Boards", "Groups", "Items", "Item Detail"]
                 .iter()
                 .map(|x| x.to_string())
                 .collect::<Vec<String>>(),
             status_labels : Vec::new(), 
             users : Vec::new(),
-            current_user : queries::current_user(&client.clone())
+            current_user : api.current_user(), 
+            selections : HashMap::new(), 
         }
     }
 }
 
-pub fn start_terminal() -> Terminal<CrosstermBackend<io::Stdout>> {
+//Owns the terminal while the TUI runs. Dropping it, or a panic anywhere, puts the shell back the
+//way it was found.
+pub struct TerminalGuard {
+    pub terminal: Terminal<CrosstermBackend<io::Stdout>>,
+}
+
+impl Deref for TerminalGuard {
+    type Target = Terminal<CrosstermBackend<io::Stdout>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.terminal
+    }
+}
+
+impl DerefMut for TerminalGuard {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.terminal
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore_terminal();
+    }
+}
+
+pub fn start_terminal() -> TerminalGuard {
+    //Restore before the panic message is printed, or it's lost with the alternate screen
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        default_hook(info);
+    }));
+
     enable_raw_mode().expect("start raw mode");
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen).expect("create alternate screen");
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture).expect("create alternate screen");
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend).expect("create new terminal");
     terminal.clear().expect("clear");
-    return terminal;
+    TerminalGuard { terminal }
 }
 
-pub fn stop_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) {
-    disable_raw_mode().expect("stop raw mode");
-    terminal.clear().unwrap();
-    terminal.show_cursor().expect("show cursor");
+//Leaves raw mode, mouse capture and the alternate screen. Safe to call more than once, and
+//errors are ignored as this also runs while panicking.
+pub fn restore_terminal() {
+    disable_raw_mode().ok();
+    execute!(io::stdout(), DisableMouseCapture, LeaveAlternateScreen, cursor::Show).ok();
 }
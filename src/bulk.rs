@@ -0,0 +1,143 @@
+use super::api;
+use super::app;
+
+//Mutations sent per request while a bulk job runs
+const BATCH_SIZE: usize = 25;
+
+#[derive(Debug, Clone)]
+pub enum BulkAction {
+    ChangeStatus(String),
+    MoveToGroup(String),
+    AssignUser(i64),
+    Archive,
+    AddUpdate(String),
+}
+
+impl BulkAction {
+    pub fn title(&self) -> String {
+        match self {
+            BulkAction::ChangeStatus(label) => format!("Change Status to {}", label),
+            BulkAction::MoveToGroup(_) => String::from("Move to Group"),
+            BulkAction::AssignUser(_) => String::from("Assign User"),
+            BulkAction::Archive => String::from("Archive"),
+            BulkAction::AddUpdate(_) => String::from("Add Update"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BulkFailure {
+    pub item_id: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct BulkJob {
+    pub action: BulkAction,
+    pub board_id: String,
+    pub status_column_id: String,
+    pub user_column_id: String,
+    pub pending: Vec<String>,
+    pub total: usize,
+    pub failures: Vec<BulkFailure>,
+}
+
+impl BulkJob {
+    pub fn new(app: &app::App, action: BulkAction) -> BulkJob {
+        let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        BulkJob {
+            action,
+            board_id: app.board_detail.id.clone(),
+            //The column picked in the status list, for boards with several
+            status_column_id: if app.status_column.is_empty() {
+                board_meta.status_column_id
+            } else {
+                app.status_column.clone()
+            },
+            user_column_id: board_meta.user_column_id,
+            pending: app.selected_items.clone(),
+            total: app.selected_items.len(),
+            failures: Vec::new(),
+        }
+    }
+
+    pub fn done(&self) -> usize {
+        self.total - self.pending.len()
+    }
+
+    pub fn finished(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    //GraphQL mutation field applying the action to one item
+    fn mutation(&self, item_id: &str) -> String {
+        let quote = |s: &str| serde_json::to_string(s).unwrap();
+        match &self.action {
+            BulkAction::ChangeStatus(label) => format!(
+                "change_column_value(item_id: {}, board_id: {}, column_id: {}, value: {}) {{ id }}",
+                item_id,
+                self.board_id,
+                quote(&self.status_column_id),
+                quote(&serde_json::json!({ "label": label }).to_string())
+            ),
+            BulkAction::MoveToGroup(group_id) => format!(
+                "move_item_to_group(item_id: {}, group_id: {}) {{ id }}",
+                item_id,
+                quote(group_id)
+            ),
+            BulkAction::AssignUser(user_id) => format!(
+                "change_column_value(item_id: {}, board_id: {}, column_id: {}, value: {}) {{ id }}",
+                item_id,
+                self.board_id,
+                quote(&self.user_column_id),
+                quote(&format!(
+                    "{{\"personsAndTeams\":[{{\"id\": {}, \"kind\": \"person\"}}]}}",
+                    user_id
+                ))
+            ),
+            BulkAction::Archive => format!("archive_item(item_id: {}) {{ id }}", item_id),
+            BulkAction::AddUpdate(body) => format!(
+                "create_update(item_id: {}, body: {}) {{ id }}",
+                item_id,
+                quote(body)
+            ),
+        }
+    }
+
+    //Sends the next batch of mutations and records any per-item failures
+    pub fn step(&mut self, api: &dyn api::MondayApi) {
+        let count = BATCH_SIZE.min(self.pending.len());
+        let batch: Vec<String> = self.pending.drain(..count).collect();
+        let fields = batch.iter().map(|id| self.mutation(id)).collect::<Vec<String>>();
+        let results = api.batch_mutation(&fields);
+        for (item_id, result) in batch.into_iter().zip(results) {
+            if let Err(message) = result {
+                self.failures.push(BulkFailure { item_id, message });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_labels_are_sent_as_json() {
+        let job = BulkJob {
+            action: BulkAction::ChangeStatus(String::from(r#"Say "hi" \o/"#)),
+            board_id: String::from("1"),
+            status_column_id: String::from("status"),
+            user_column_id: String::new(),
+            pending: vec![String::from("2")],
+            total: 1,
+            failures: Vec::new(),
+        };
+        let mutation = job.mutation("2");
+        let (_, value) = mutation.split_once("value: ").unwrap();
+        let value = value.trim_end_matches(") { id }");
+        let value: String = serde_json::from_str(value).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&value).unwrap();
+        assert_eq!(value["label"], r#"Say "hi" \o/"#);
+    }
+}
@@ -1,8 +1,28 @@
 use dirs::home_dir;
 use serde::{Deserialize, Serialize};
 use serde_json;
+use std::collections::HashMap;
 use std::fs::OpenOptions;
+use super::api;
 use super::app; 
+use super::filter;
+use super::objects;
+use super::sort; 
+
+//Table column width used when none has been configured
+pub const DEFAULT_COLUMN_WIDTH: u16 = 15;
+
+//A named filter, sort and column set for a board
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedView {
+    pub name: String,
+    pub filter: String,
+    pub sort: Option<sort::ItemSort>,
+    pub visible_columns: Vec<String>,
+    //Filter chips switched on when the view was saved
+    #[serde(default)]
+    pub chips: filter::FilterState,
+}
 
 // To store status column
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,6 +30,20 @@ pub struct BoardMeta {
     pub id: String,
     pub status_column_id: String,
     pub user_column_id: String,
+    //Columns shown in the item table, in order. Empty means the defaults.
+    #[serde(default)]
+    pub visible_columns: Vec<String>,
+    #[serde(default)]
+    pub column_widths: HashMap<String, u16>,
+    #[serde(default)]
+    pub sort: Option<sort::ItemSort>,
+    #[serde(default)]
+    pub views: Vec<SavedView>,
+    //Name of the view applied whenever the board's items are opened
+    #[serde(default)]
+    pub default_view: Option<String>,
+    #[serde(default)]
+    pub favorite: bool,
 }
 
 impl BoardMeta {
@@ -17,25 +51,100 @@ impl BoardMeta {
         BoardMeta {
             id: String::from(""),
             status_column_id: String::from(""),
-            user_column_id : String::from("")
+            user_column_id : String::from(""),
+            visible_columns : Vec::new(),
+            column_widths : HashMap::new(),
+            sort : None,
+            views : Vec::new(),
+            default_view : None,
+            favorite : false,
         } 
     }
+
+    //Fills in the status and people columns from the board's schema, keeping choices
+    //that still exist. Returns whether anything changed.
+    pub fn detect_columns(&mut self, columns: &[objects::Column]) -> bool {
+        let exists = |id: &str| columns.iter().any(|c| c.id == id);
+        let mut changed = false;
+        if !exists(&self.status_column_id) {
+            let detected = columns.iter().find(|c| c.is_status()).map(|c| c.id.clone()).unwrap_or_default();
+            changed |= detected != self.status_column_id;
+            self.status_column_id = detected;
+        }
+        if !exists(&self.user_column_id) {
+            let detected = columns.iter().find(|c| c.is_people()).map(|c| c.id.clone()).unwrap_or_default();
+            changed |= detected != self.user_column_id;
+            self.user_column_id = detected;
+        }
+        changed
+    }
+
+    pub fn get_view(&self, name: &str) -> Option<&SavedView> {
+        self.views.iter().find(|view| view.name == name)
+    }
+
+    pub fn column_width(&self, column_id: &str) -> u16 {
+        *self.column_widths.get(column_id).unwrap_or(&DEFAULT_COLUMN_WIDTH)
+    }
+}
+
+//Recently opened boards, groups and items, most recent first
+pub const MAX_RECENT: usize = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RecentKind {
+    Board,
+    Group,
+    Item,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recent {
+    pub kind: RecentKind,
+    pub board: objects::Board,
+    //Empty for boards
+    pub group_id: String,
+    pub group_title: String,
+    //Empty for boards and groups
+    pub item_id: String,
+    pub item_name: String,
+}
+
+impl Recent {
+    pub fn title(&self) -> &str {
+        match self.kind {
+            RecentKind::Board => &self.board.name,
+            RecentKind::Group => &self.group_title,
+            RecentKind::Item => &self.item_name,
+        }
+    }
+
+    //Where the entry lives, e.g. "Roadmap › Backlog"
+    pub fn breadcrumb(&self) -> String {
+        match self.kind {
+            RecentKind::Board => String::new(),
+            RecentKind::Group => self.board.name.clone(),
+            RecentKind::Item => format!("{} › {}", self.board.name, self.group_title),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Cache {
     pub boards: Vec<BoardMeta>,
-    pub app_state : app::AppState
+    pub app_state : app::AppState,
+    #[serde(default)]
+    pub recent: Vec<Recent>,
 }
 
 impl Cache {
-    pub fn new() -> Cache {
+    pub fn new(api: &dyn api::MondayApi) -> Cache {
         if exists() {
             return read();
         } else {
             create().expect("could not create .monday dir");
-            let cache = Cache { boards: vec![], app_state : app::AppState::new()};
-            write(&cache.clone()).expect("could not write");
+            let cache = Cache { boards: vec![], app_state : app::AppState::new(api), recent: vec![]};
+            write(&get_cache_dir(), &cache.clone()).expect("could not write");
             return cache;
         }
     }
@@ -49,6 +158,17 @@ impl Cache {
         }
     }
 
+    //Moves the entry to the front, dropping the oldest past MAX_RECENT
+    pub fn add_recent(&mut self, recent : Recent) {
+        self.recent.retain(|r| !(r.kind == recent.kind && r.board.id == recent.board.id && r.group_id == recent.group_id && r.item_id == recent.item_id));
+        self.recent.insert(0, recent);
+        self.recent.truncate(MAX_RECENT);
+    }
+
+    pub fn remove_board_meta(&mut self, board_id : &str) {
+        self.boards.retain(|board| board.id != board_id);
+    }
+
     pub fn board_has_meta(&self, board_id : String) -> bool {
         self.boards.iter().filter(|board| board.id == board_id).cloned().collect::<Vec<BoardMeta>>().len() > 0
     }
@@ -80,9 +200,9 @@ pub fn read() -> Cache {
     return res;
 }
 
-pub fn write(cache: &Cache) -> Result<(), std::io::Error> {
-    let file_path = get_cache_path();
-    if exists() {
+pub fn write(dir: &str, cache: &Cache) -> Result<(), std::io::Error> {
+    let file_path = cache_path(dir);
+    if std::path::Path::new(&file_path).exists() {
         std::fs::remove_file(file_path.clone()).expect("could not remove file");
     }
     let file = OpenOptions::new()
@@ -95,12 +215,18 @@ pub fn write(cache: &Cache) -> Result<(), std::io::Error> {
 }
 
 pub fn get_cache_path() -> String {
-    let home_dir = home_dir().unwrap().into_os_string().into_string().unwrap();
-    let file_path = format!("{}/{}", home_dir, ".monday/cache.json");
-    return file_path;
+    cache_path(&get_cache_dir())
+}
+
+pub fn cache_path(dir: &str) -> String {
+    format!("{}/{}", dir, "cache.json")
 }
 
+//MONDAY_DIR replaces ~/.monday, e.g. to keep the demo account's files apart
 pub fn get_cache_dir() -> String {
+    if let Ok(dir) = std::env::var("MONDAY_DIR") {
+        return dir;
+    }
     let home_dir = home_dir().unwrap().into_os_string().into_string().unwrap();
     let file_path = format!("{}/{}", home_dir, ".monday");
     return file_path;
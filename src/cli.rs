@@ -0,0 +1,215 @@
+use super::api;
+use super::cache;
+use super::filter;
+use super::objects;
+use super::utils;
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use std::rc::Rc;
+
+pub fn build() -> App<'static, 'static> {
+    App::new("Monday TUI")
+        .version("0.1.1")
+        .author("Nate D.")
+        .about("Monday Terminal User Interface for very basic interaction with Monday.com project management software.")
+        .arg(
+            Arg::with_name("demo")
+                .long("demo")
+                .global(true)
+                .help("Use a built-in sample account instead of monday.com"),
+        )
+        .subcommand(
+            SubCommand::with_name("board")
+                .about("Manage boards without starting the TUI")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("create")
+                        .about("Create a new board and print its id")
+                        .arg(Arg::with_name("name").required(true))
+                        .arg(
+                            Arg::with_name("kind")
+                                .long("kind")
+                                .takes_value(true)
+                                .possible_values(&["public", "private", "share"])
+                                .default_value("public"),
+                        )
+                        .arg(
+                            Arg::with_name("workspace")
+                                .long("workspace")
+                                .takes_value(true)
+                                .help("Workspace id to place the board in"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("duplicate")
+                        .about("Duplicate a board (e.g. a template) and print the new id")
+                        .arg(Arg::with_name("board_id").required(true))
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .takes_value(true)
+                                .help("Name of the new board"),
+                        )
+                        .arg(
+                            Arg::with_name("type")
+                                .long("type")
+                                .takes_value(true)
+                                .possible_values(&["structure", "pulses", "pulses-and-updates"])
+                                .default_value("structure"),
+                        )
+                        .arg(
+                            Arg::with_name("workspace")
+                                .long("workspace")
+                                .takes_value(true)
+                                .help("Workspace id to place the board in"),
+                        )
+                        .arg(
+                            Arg::with_name("keep_subscribers")
+                                .long("keep-subscribers")
+                                .help("Copy the subscribers of the original board"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("archive")
+                        .about("Archive a board")
+                        .arg(Arg::with_name("board_id").required(true)),
+                )
+                .subcommand(
+                    SubCommand::with_name("subscribe")
+                        .about("Add users as subscribers of a board")
+                        .arg(Arg::with_name("board_id").required(true))
+                        .arg(Arg::with_name("user_ids").required(true).multiple(true))
+                        .arg(
+                            Arg::with_name("owner")
+                                .long("owner")
+                                .help("Add the users as board owners"),
+                        ),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("items")
+                .about("List the items of a board, one \"id<TAB>name\" per line")
+                .arg(Arg::with_name("board_id").required(true))
+                .arg(
+                    Arg::with_name("group")
+                        .long("group")
+                        .takes_value(true)
+                        .help("Only list items in this group"),
+                )
+                .arg(
+                    Arg::with_name("filter")
+                        .long("filter")
+                        .takes_value(true)
+                        .help("Filter query, e.g. 'status:\"Working on it\" assignee:me due<7d'"),
+                ),
+        )
+}
+
+fn parse_workspace(matches: &ArgMatches) -> Result<Option<i64>, String> {
+    match matches.value_of("workspace") {
+        Some(w) => w
+            .parse::<i64>()
+            .map(Some)
+            .map_err(|_| format!("invalid workspace id: {}", w)),
+        None => Ok(None),
+    }
+}
+
+//Board ids are numeric; checked here so a typo is reported instead of failing in the query
+fn parse_board_id(matches: &ArgMatches) -> Result<String, String> {
+    let board_id = matches.value_of("board_id").unwrap();
+    board_id
+        .parse::<i64>()
+        .map(|_| board_id.to_string())
+        .map_err(|_| format!("invalid board id: {}", board_id))
+}
+
+fn run_board(matches: &ArgMatches, api: &dyn api::MondayApi) -> Result<(), String> {
+    match matches.subcommand() {
+        ("create", Some(m)) => {
+            let kind = m.value_of("kind").unwrap().parse::<objects::BoardKind>()?;
+            let name = m.value_of("name").unwrap().to_string();
+            let id = api.create_board(name, kind, parse_workspace(m)?);
+            println!("{}", id);
+        }
+        ("duplicate", Some(m)) => {
+            let duplicate_type = m
+                .value_of("type")
+                .unwrap()
+                .parse::<objects::DuplicateBoardType>()?;
+            let id = api.duplicate_board(
+                parse_board_id(m)?,
+                duplicate_type,
+                m.value_of("name").map(|n| n.to_string()),
+                parse_workspace(m)?,
+                m.is_present("keep_subscribers"),
+            );
+            println!("{}", id);
+        }
+        ("archive", Some(m)) => {
+            api.archive_board(parse_board_id(m)?);
+        }
+        ("subscribe", Some(m)) => {
+            let user_ids = m
+                .values_of("user_ids")
+                .unwrap()
+                .map(|u| {
+                    u.parse::<i64>()
+                        .map_err(|_| format!("invalid user id: {}", u))
+                })
+                .collect::<Result<Vec<i64>, String>>()?;
+            api.add_subscribers(
+                parse_board_id(m)?,
+                user_ids,
+                m.is_present("owner"),
+            );
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn run_items(matches: &ArgMatches, api: &dyn api::MondayApi) -> Result<(), String> {
+    let board_id = parse_board_id(matches)?;
+    let items = match matches.value_of("group") {
+        Some(group_id) => api.item_list(board_id.clone(), group_id.to_string()),
+        None => api.board_items(board_id.clone()),
+    };
+    let items = match matches.value_of("filter") {
+        Some(query) => {
+            //Validate up front rather than falling back to a plain search
+            filter::parse(query)?;
+            let board_meta = if cache::exists() {
+                cache::read().get_board_meta(board_id)
+            } else {
+                cache::BoardMeta::new()
+            };
+            let current_user = api.current_user();
+            let ctx = filter::Context::new(&current_user, &board_meta);
+            utils::query_items(query, &items, &ctx)
+        }
+        None => items,
+    };
+    for item in items {
+        println!("{}\t{}", item.id, item.name);
+    }
+    Ok(())
+}
+
+//Runs a non-interactive subcommand. Returns false when the TUI should start instead.
+pub fn run(matches: &ArgMatches) -> Result<bool, String> {
+    match matches.subcommand() {
+        ("board", Some(m)) => run_board(m, &*get_api(matches)?)?,
+        ("items", Some(m)) => run_items(m, &*get_api(matches)?)?,
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+//The sample account with --demo, monday.com otherwise
+pub fn get_api(matches: &ArgMatches) -> Result<Rc<dyn api::MondayApi>, String> {
+    if matches.is_present("demo") {
+        return Ok(Rc::new(api::FakeApi::demo()));
+    }
+    let api = api::HttpApi::new()?;
+    Ok(Rc::new(api))
+}
@@ -1,20 +1,39 @@
 use super::app;
+use super::fuzzy;
+use super::keymap;
+use super::mouse;
+use super::utils;
 use super::views; 
-use std::io;
 use tui::{
-    backend::CrosstermBackend,
+    backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     terminal::Frame,
     text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, Paragraph, Tabs},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs, Wrap},
 };
 
-pub fn get_default_chunks(rect: &Frame<CrosstermBackend<io::Stdout>>) -> Vec<Rect> {
+//Terminals narrower than this get side panes dropped and shortened menu tabs
+pub const NARROW_WIDTH: u16 = 80;
+
+pub fn is_narrow(size: Rect) -> bool {
+    size.width < NARROW_WIDTH
+}
+
+//Space around the screen, given up on small terminals
+pub fn get_margin(size: Rect) -> u16 {
+    if is_narrow(size) || size.height < 24 {
+        0
+    } else {
+        2
+    }
+}
+
+pub fn get_default_chunks<B: Backend>(rect: &Frame<B>) -> Vec<Rect> {
     let size = rect.size();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .margin(2)
+        .margin(get_margin(size))
         .constraints(
             [
                 Constraint::Length(3),
@@ -27,15 +46,20 @@ pub fn get_default_chunks(rect: &Frame<CrosstermBackend<io::Stdout>>) -> Vec<Rec
     return chunks;
 }
 
-pub fn get_help_chunks(rect: &Frame<CrosstermBackend<io::Stdout>>) -> Vec<Rect> {
+pub fn get_help_chunks<B: Backend>(rect: &Frame<B>) -> Vec<Rect> {
+    get_help_chunks_with_height(rect, 3)
+}
+
+//Help bar tall enough for a second line, e.g. the filter chips
+pub fn get_help_chunks_with_height<B: Backend>(rect: &Frame<B>, help_height: u16) -> Vec<Rect> {
     let size = rect.size();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .margin(2)
+        .margin(get_margin(size))
         .constraints(
             [
                 Constraint::Length(3),
-                Constraint::Length(3),
+                Constraint::Length(help_height),
                 Constraint::Min(2),
                 Constraint::Length(3),
             ]
@@ -46,12 +70,58 @@ pub fn get_help_chunks(rect: &Frame<CrosstermBackend<io::Stdout>>) -> Vec<Rect>
 }
 
 
+//Keeps a list's selection inside the list after its data reloads or shrinks; an empty list has none
+pub fn clamp_selection(list_state: &mut ListState, len: usize) {
+    if len == 0 {
+        list_state.select(None);
+        return;
+    }
+    let selected = list_state.selected().unwrap_or(0).min(len - 1);
+    list_state.select(Some(selected));
+}
+
+//Draws a view's list and remembers where its rows are, so a click can select one
+pub fn render_list<B: Backend>(
+    rect: &mut Frame<B>,
+    list: List,
+    area: Rect,
+    len: usize,
+    list_state: &mut ListState,
+    targets: &mut mouse::Targets,
+) {
+    clamp_selection(list_state, len);
+    let rows = Block::default().borders(Borders::ALL).inner(area);
+    let selected = list_state.selected().unwrap_or(0);
+    targets.list_offset = mouse::list_offset(targets.list_offset, selected, len, rows.height as usize);
+    targets.list = Some(mouse::ListTarget { area: rows, offset: targets.list_offset, len });
+    rect.render_stateful_widget(list, area, list_state);
+}
+
+//Row of an options list, asking for a second Enter while it waits to be confirmed
+pub fn option_item(app: &app::App, index: usize, label: &str) -> ListItem<'static> {
+    if app.confirming == Some((app.active_menu_item, index)) {
+        ListItem::new(Span::styled(
+            format!("{} (Enter again to confirm)", label),
+            Style::default().fg(Color::LightRed),
+        ))
+    } else {
+        ListItem::new(label.to_string())
+    }
+}
+
 pub fn get_search_block(app: &app::App) -> Paragraph {
     let search_text: String = app
         .key_input
         .iter()
         .map(|x| x.to_string())
         .collect::<String>();
+    //In normal mode letters are commands, so say how to start typing
+    let search_keys = app.keymap.keys_for(keymap::SEARCH);
+    let title = if app.is_typing() || search_keys.is_empty() {
+        String::from("Search")
+    } else {
+        format!("Search ({} to type)", search_keys.join(", "))
+    };
     return Paragraph::new(search_text)
         .style(Style::default().fg(Color::LightCyan))
         .alignment(Alignment::Center)
@@ -59,17 +129,28 @@ pub fn get_search_block(app: &app::App) -> Paragraph {
             Block::default()
                 .borders(Borders::ALL)
                 .style(Style::default().fg(Color::White))
-                .title("Search")
+                .title(title)
                 .border_type(BorderType::Plain),
         );
 }
 
-pub fn get_menu_block(app: &app::App) -> Tabs {
-    let menu = app
-        .menu_titles
-        .iter()
+//Menu titles, cut to an equal share of the width when they don't all fit
+pub fn fit_menu_titles(titles: &[String], width: u16) -> Vec<String> {
+    //Each tab has a space before and a space and divider after
+    let needed = titles.iter().map(|t| t.chars().count() + 3).sum::<usize>();
+    if needed <= width as usize || titles.is_empty() {
+        return titles.to_vec();
+    }
+    let share = (width as usize / titles.len()).saturating_sub(3).max(1);
+    titles.iter().map(|t| t.chars().take(share).collect()).collect()
+}
+
+pub fn get_menu_block(app: &app::App, width: u16) -> Tabs<'static> {
+    let menu = fit_menu_titles(&app.menu_titles, width.saturating_sub(2))
+        .into_iter()
         .map(|t| {
-            let (first, rest) = t.split_at(1);
+            let first = t.chars().take(1).collect::<String>();
+            let rest = t.chars().skip(1).collect::<String>();
             Spans::from(vec![
                 Span::styled(
                     first,
@@ -91,41 +172,242 @@ pub fn get_menu_block(app: &app::App) -> Tabs {
     return tabs;
 }
 
+//Draws the menu and remembers where its tabs are, so a click on one opens its view
+pub fn render_menu<B: Backend>(rect: &mut Frame<B>, area: Rect, app: &mut app::App) {
+    rect.render_widget(get_menu_block(app, area.width), area);
+    let titles = fit_menu_titles(&app.menu_titles, area.width.saturating_sub(2));
+    app.mouse.tabs = mouse::tab_rects(area, &titles)
+        .into_iter()
+        .zip(mouse::TAB_VIEWS.iter().copied())
+        .collect();
+}
+
+//Text drawn in one style, with the URL it opens if it's a link
+pub type Piece = (String, Style, Option<String>);
+
+//Word wraps lines of styled text to a width, underlining links so they can be drawn as click
+//targets. Words longer than a whole row are split.
+pub fn wrap_lines(lines: Vec<Vec<(String, Style)>>, width: usize) -> Vec<Vec<Piece>> {
+    let mut rows = Vec::new();
+    for line in lines {
+        let mut row: Vec<Piece> = Vec::new();
+        let mut used = 0;
+        for (text, style, link) in link_pieces(line) {
+            for word in words(&text) {
+                let blank = word.trim().is_empty();
+                let mut word = word.to_string();
+                let mut length = word.chars().count();
+                if used > 0 && used + length > width {
+                    rows.push(std::mem::take(&mut row));
+                    used = 0;
+                    if blank {
+                        continue;
+                    }
+                }
+                while length > width && width > 0 {
+                    let rest = word.split_off(word.char_indices().nth(width).map(|(i, _)| i).unwrap_or(word.len()));
+                    push_piece(&mut row, (word, style, link.clone()));
+                    rows.push(std::mem::take(&mut row));
+                    word = rest;
+                    length -= width;
+                }
+                used += length;
+                push_piece(&mut row, (word, style, link.clone()));
+            }
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+//Splits out the links in each piece of a line
+fn link_pieces(line: Vec<(String, Style)>) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    for (text, style) in line {
+        let mut from = 0;
+        for (start, end) in mouse::find_links(&text) {
+            pieces.push((text[from..start].to_string(), style, None));
+            let url = text[start..end].to_string();
+            pieces.push((url.clone(), style.fg(Color::LightCyan).add_modifier(Modifier::UNDERLINED), Some(url)));
+            from = end;
+        }
+        pieces.push((text[from..].to_string(), style, None));
+    }
+    pieces
+}
+
+//Runs of spaces and runs of everything else
+fn words(text: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    for (i, c) in text.char_indices().skip(1) {
+        let previous = text[..i].chars().last().map(char::is_whitespace);
+        if previous != Some(c.is_whitespace()) {
+            words.push(&text[start..i]);
+            start = i;
+        }
+    }
+    if start < text.len() {
+        words.push(&text[start..]);
+    }
+    words
+}
+
+//Joins text onto the last piece when it looks the same, so a link stays one click target per row
+fn push_piece(row: &mut Vec<Piece>, piece: Piece) {
+    if piece.0.is_empty() {
+        return;
+    }
+    match row.last_mut() {
+        Some(last) if last.1 == piece.1 && last.2 == piece.2 => last.0.push_str(&piece.0),
+        _ => row.push(piece),
+    }
+}
+
+//Spans for a list entry with the characters matched by a fuzzy search emphasized
+pub fn highlight_matches(text: &str, query: &str) -> Vec<Span<'static>> {
+    let positions = match fuzzy::fuzzy_match(query, text) {
+        Some(found) if !found.positions.is_empty() => found.positions,
+        _ => return vec![Span::raw(text.to_string())],
+    };
+    let matched = Style::default()
+        .fg(Color::LightCyan)
+        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (i, c) in text.chars().enumerate() {
+        let is_match = positions.binary_search(&i).is_ok();
+        if is_match != run_matched && !run.is_empty() {
+            let style = if run_matched { matched } else { Style::default() };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_matched = is_match;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        let style = if run_matched { matched } else { Style::default() };
+        spans.push(Span::styled(run, style));
+    }
+    spans
+}
+
+pub fn get_filter_chips(app: &app::App) -> Spans<'static> {
+    let mut spans = Vec::new();
+    for chip in utils::item_filter(app).chips(&app.groups) {
+        spans.push(Span::styled(
+            format!(" {} ", chip),
+            Style::default().fg(Color::Black).bg(Color::LightCyan),
+        ));
+        spans.push(Span::raw(" "));
+    }
+    Spans::from(spans)
+}
+
 pub fn get_help_block(app: &app::App) -> Paragraph {
-    let spans = match app.active_menu_item {
-        views::MenuItem::Items => {
+    //Entries come from the active view
+    let spans = Spans::from(
+        app.help
+            .iter()
+            .flat_map(|(key, description)| {
+                vec![
+                    Span::styled(format!("{}: ", key), Style::default().fg(Color::LightCyan)),
+                    Span::styled(format!("{} ", description), Style::default().fg(Color::White)),
+                ]
+            })
+            .collect::<Vec<Span>>(),
+    );
+
+    //Active filters show as chips on a second line
+    let mut lines = vec![spans];
+    if let views::MenuItem::Items = app.active_menu_item {
+        let chips = get_filter_chips(app);
+        if !chips.0.is_empty() {
+            lines.push(chips);
+        }
+    }
+
+    Paragraph::new(lines)
+        .alignment(Alignment::Left)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title("Help")
+                .border_type(BorderType::Plain),
+        )
+}
+
+
+pub fn get_input_block<'a>(app: &app::App, label: &'a str, title: &'a str) -> Paragraph<'a> {
+    //Key input as string
+    let input_text: String = app
+        .key_input
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<String>();
+
+    let input_span = vec![Spans::from(vec![
+        Span::styled(
+            label,
+            Style::default()
+                .add_modifier(Modifier::ITALIC)
+                .fg(Color::LightBlue),
+        ),
+        Span::raw(input_text),
+    ])];
+
+    Paragraph::new(input_span)
+        .style(Style::default())
+        .alignment(Alignment::Left)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White))
+                .title(title)
+                .border_type(BorderType::Plain),
+        )
+        .wrap(Wrap { trim: true })
+}
+
+//Key bindings for the current view, drawn over it when the help key is pressed
+pub fn render_keymap_overlay<B: Backend>(rect: &mut Frame<B>, app: &app::App) {
+    let view = format!("{:?}", app.active_menu_item);
+    let described = app.keymap.describe_view(&view);
+    let key_width = described.iter().map(|(keys, _)| keys.chars().count()).max().unwrap_or(0);
+    let lines = described
+        .into_iter()
+        .map(|(keys, description)| {
             Spans::from(vec![
                 Span::styled(
-                    "F1: ",
-                    Style::default()
-                        .fg(Color::LightCyan)
+                    format!("{:width$}  ", keys, width = key_width),
+                    Style::default().fg(Color::LightCyan),
                 ),
-                Span::styled("Show All Items ", Style::default().fg(Color::White)),
-                Span::styled(
-                    "F2: ",
-                    Style::default()
-                        .fg(Color::LightCyan)
-                ),
-                Span::styled("Show Subscribed ", Style::default().fg(Color::White)),
-                Span::styled(
-                    "F3: ",
-                    Style::default()
-                        .fg(Color::LightCyan)
-                ),
-                Span::styled("Create Item ", Style::default().fg(Color::White)),
+                Span::styled(description, Style::default().fg(Color::White)),
             ])
-        },
-        _ => {Spans::from(vec![])}
-    };
+        })
+        .collect::<Vec<Spans>>();
 
-    Paragraph::new(spans)
+    //Centered, sized to the bindings where the terminal allows
+    let size = rect.size();
+    let height = (lines.len() as u16 + 2).min(size.height);
+    let width = (size.width * 2 / 3).max(40).min(size.width);
+    let area = Rect::new(
+        size.x + (size.width - width) / 2,
+        size.y + (size.height - height) / 2,
+        width,
+        height,
+    );
+    let overlay = Paragraph::new(lines)
+        .style(Style::default())
         .alignment(Alignment::Left)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .style(Style::default().fg(Color::White))
-                .title("Help")
+                .title(format!("Key Bindings: {} ({} keymap, any key to close)", view, app.keymap.preset))
                 .border_type(BorderType::Plain),
-        )
+        );
+    rect.render_widget(Clear, area);
+    rect.render_widget(overlay, area);
 }
-
@@ -0,0 +1,101 @@
+use super::app;
+use super::cache;
+use super::filter;
+use super::objects;
+
+//"My work" on the Home view: every item assigned to the current user across all boards
+
+#[derive(Debug, Clone)]
+pub enum Row {
+    //Section title and the number of items in it
+    Header(String, usize),
+    Item(Box<objects::Item>),
+}
+
+fn status_text(item: &objects::Item, board_meta: &cache::BoardMeta) -> String {
+    item.column_values
+        .iter()
+        .find(|cv| {
+            if board_meta.status_column_id.is_empty() {
+                cv.type_ == "color"
+            } else {
+                cv.id == board_meta.status_column_id
+            }
+        })
+        .map(|cv| cv.text.clone())
+        .filter(|text| !text.is_empty())
+        .unwrap_or_else(|| String::from("No Status"))
+}
+
+//Due date as days since the epoch, from the item's first date column
+pub fn due_day(item: &objects::Item) -> Option<i64> {
+    item.column_values
+        .iter()
+        .find(|cv| cv.type_ == "date")
+        .and_then(|cv| filter::parse_date(&cv.text))
+}
+
+pub fn is_overdue(item: &objects::Item, today: i64) -> bool {
+    matches!(due_day(item), Some(due) if due < today)
+}
+
+fn due_section(item: &objects::Item, today: i64) -> (usize, &'static str) {
+    match due_day(item) {
+        Some(due) if due < today => (0, "Overdue"),
+        Some(due) if due == today => (1, "Due Today"),
+        Some(due) if due <= today + 7 => (2, "Due This Week"),
+        Some(_) => (3, "Due Later"),
+        None => (4, "No Due Date"),
+    }
+}
+
+//Fetches the items of every loaded board and keeps the ones assigned to the current user.
+//A board's cached people column is used when it has been picked, otherwise any people column.
+//Boards that fail to load are left out and reported in the error, the rest are still shown.
+pub fn load(app: &mut app::App) -> (Vec<objects::Item>, Option<String>) {
+    if app.boards.is_empty() {
+        app.boards = app.api.board_list();
+    }
+    let board_ids = app.boards.iter().map(|b| b.id.clone()).collect::<Vec<String>>();
+    let (items, errors) = app.api.items_across_boards(&board_ids);
+    let assigned = filter::FilterState {
+        assigned_to_me: true,
+        ..filter::FilterState::default()
+    };
+    let items = items
+        .into_iter()
+        .filter(|item| {
+            let board_meta = app.cache.get_board_meta(item.board.id.clone());
+            let ctx = filter::Context::new(&app.current_user, &board_meta);
+            assigned.matches(item, &ctx)
+        })
+        .collect();
+    let error = if errors.is_empty() { None } else { Some(errors.join("; ")) };
+    (items, error)
+}
+
+//Sections by status, or by due date when by_due is set. Items are ordered by due date inside a section.
+pub fn rows(items: &[objects::Item], by_due: bool, today: i64, cache: &cache::Cache) -> Vec<Row> {
+    let mut sections: Vec<((usize, String), Vec<objects::Item>)> = Vec::new();
+    for item in items {
+        let key = if by_due {
+            let (order, title) = due_section(item, today);
+            (order, title.to_string())
+        } else {
+            (0, status_text(item, &cache.get_board_meta(item.board.id.clone())))
+        };
+        match sections.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, section)) => section.push(item.clone()),
+            None => sections.push((key, vec![item.clone()])),
+        }
+    }
+    sections.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut rows = Vec::new();
+    for ((_, title), mut section) in sections {
+        //Undated items last
+        section.sort_by_key(|item| due_day(item).unwrap_or(i64::MAX));
+        rows.push(Row::Header(title, section.len()));
+        rows.extend(section.into_iter().map(|item| Row::Item(Box::new(item))));
+    }
+    rows
+}
@@ -1,12 +1,21 @@
 use super::app;
-use crossterm::event::{self, Event as CEvent, KeyCode, KeyEvent};
+use super::history;
+use super::keymap;
+use super::mouse;
+use super::router;
+use super::views;
+use crossterm::event::{self, Event as CEvent, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, Instant};
+use tui::backend::Backend;
 
 //Event loop enum
 pub enum Event<I> {
     Input(I),
+    Mouse(MouseEvent),
+    //The terminal changed size
+    Resize,
     Tick,
 }
 
@@ -22,8 +31,10 @@ pub fn start_input_handling() -> mpsc::Receiver<Event<KeyEvent>> {
                 .checked_sub(last_tick.elapsed())
                 .unwrap_or_else(|| Duration::from_secs(0));
             if event::poll(timeout).unwrap() {
-                if let CEvent::Key(key) = event::read().unwrap() {
-                    tx.send(Event::Input(key)).unwrap();
+                match event::read().unwrap() {
+                    CEvent::Key(key) => tx.send(Event::Input(key)).unwrap(),
+                    CEvent::Mouse(mouse) => tx.send(Event::Mouse(mouse)).unwrap(),
+                    CEvent::Resize(_, _) => tx.send(Event::Resize).unwrap(),
                 }
             }
             if last_tick.elapsed() >= tick_rate {
@@ -35,13 +46,144 @@ pub fn start_input_handling() -> mpsc::Receiver<Event<KeyEvent>> {
     return rx;
 }
 
+//Typing only reaches the search box or text field outside of normal mode
 pub fn handle_key_input(event: KeyEvent, app: &mut app::App) {
+    if !app.is_typing() {
+        return;
+    }
     match event.code {
         KeyCode::Char(c) => app.key_input.push(c),
         KeyCode::Backspace => {
             app.key_input.pop();
-            ()
         }
         _ => {}
     }
 }
+
+//Everything a key press does, from the keymap and input mode to the active view.
+//Returns true when the key asks to quit.
+pub fn handle_input<B: Backend>(event: KeyEvent, app: &mut app::App, router: &router::Router<B>) -> bool {
+    let view = format!("{:?}", app.active_menu_item);
+
+    //Any key closes the key bindings overlay
+    if app.show_keymap {
+        app.show_keymap = false;
+        return false;
+    }
+
+    //Enter or Esc ends a search started from normal mode, keeping the query
+    let base_mode = app.keymap.base_mode(&view);
+    if app.input_mode == app::InputMode::Search
+        && base_mode == app::InputMode::Normal
+        && (event.code == KeyCode::Esc || event.code == KeyCode::Enter)
+    {
+        app.input_mode = app::InputMode::Normal;
+        return false;
+    }
+
+    //Letters are typed, not looked up, in search and insert mode
+    let typed = matches!(event.code, KeyCode::Char(_))
+        && app.is_typing()
+        && !event.modifiers.contains(KeyModifiers::CONTROL);
+    let action = if typed { None } else { app.keymap.action(&view, &event) };
+
+    //Keys the keymap file moved to other actions do nothing
+    if !typed && app.keymap.is_replaced(&view, &event) {
+        return false;
+    }
+    match action.map(|a| a.name) {
+        Some(keymap::QUIT) => return true,
+        Some(keymap::QUICK_SWITCH) => {
+            views::QuickSwitch.open(app);
+            return false;
+        }
+        Some(keymap::HISTORY_BACK) => {
+            history::go_back(app);
+            return false;
+        }
+        Some(keymap::HISTORY_FORWARD) => {
+            history::go_forward(app);
+            return false;
+        }
+        Some(keymap::HELP) => {
+            app.show_keymap = true;
+            return false;
+        }
+        Some(keymap::SEARCH) => {
+            if keymap::is_searchable(&view) {
+                app.input_mode = app::InputMode::Search;
+                app.key_input = Vec::new();
+            }
+            return false;
+        }
+        _ => {}
+    }
+
+    //Bound keys reach the view as the key it handles
+    let event = match action {
+        Some(_) => app.keymap.translate(&view, event),
+        None => event,
+    };
+
+    // Key Input
+    if !router.takes_key(&event, app) {
+        handle_key_input(event, app);
+    }
+
+    //View events
+    router.handle_event(event, app);
+    false
+}
+
+//Clicks and the scroll wheel, matched against what the last frame drew
+pub fn handle_mouse<B: Backend>(event: MouseEvent, app: &mut app::App, router: &router::Router<B>) {
+    //Any click closes the key bindings overlay
+    if app.show_keymap {
+        if let MouseEventKind::Down(_) = event.kind {
+            app.show_keymap = false;
+        }
+        return;
+    }
+
+    let (column, row) = (event.column, event.row);
+    match event.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if let Some(menu_item) = app.mouse.tab_at(column, row) {
+                views::open_tab(app, menu_item);
+            } else if let Some(url) = app.mouse.link_at(column, row) {
+                //There's nowhere to show a failure, and the link is on screen to copy
+                mouse::open_link(&url).ok();
+            } else if let Some(index) = app.mouse.row_at(column, row) {
+                //Clicking the selected row opens it, like Enter
+                if app.list_state.selected() == Some(index) {
+                    router.handle_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE), app);
+                } else {
+                    app.list_state.select(Some(index));
+                }
+            }
+        }
+        MouseEventKind::ScrollDown => scroll(app, router, true),
+        MouseEventKind::ScrollUp => scroll(app, router, false),
+        _ => {}
+    }
+}
+
+//The wheel moves a list's selection without wrapping around; other views get it as Up or Down,
+//e.g. item detail scrolls its update thread
+fn scroll<B: Backend>(app: &mut app::App, router: &router::Router<B>, down: bool) {
+    match router.view(app.active_menu_item).and_then(|view| view.list_len(app)) {
+        Some(len) => {
+            let selected = app.list_state.selected().unwrap_or(0);
+            let next = if down {
+                (selected + 1).min(len.saturating_sub(1))
+            } else {
+                selected.saturating_sub(1)
+            };
+            app.list_state.select(Some(next));
+        }
+        None => {
+            let code = if down { KeyCode::Down } else { KeyCode::Up };
+            router.handle_event(KeyEvent::new(code, KeyModifiers::NONE), app);
+        }
+    }
+}
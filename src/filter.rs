@@ -0,0 +1,645 @@
+use super::cache;
+use super::objects;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+//Filter language for items, e.g.
+//  status:"Working on it" assignee:me due<7d -label:Done name~api
+//Terms are ANDed together, a leading '-' negates a term and bare words match the item name.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Eq,
+    Contains,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub negated: bool,
+    //None for a bare word
+    pub field: Option<String>,
+    pub op: Op,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Filter {
+    pub conditions: Vec<Condition>,
+}
+
+//What a filter is evaluated against besides the item itself
+pub struct Context<'a> {
+    pub current_user: &'a objects::User,
+    pub board_meta: &'a cache::BoardMeta,
+    //Days since the unix epoch
+    pub today: i64,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(current_user: &'a objects::User, board_meta: &'a cache::BoardMeta) -> Context<'a> {
+        Context {
+            current_user,
+            board_meta,
+            today: today(),
+        }
+    }
+}
+
+//Days since the unix epoch, in UTC
+pub fn today() -> i64 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    (secs / 86400) as i64
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while let Some((_, c)) = self.chars.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.chars.next();
+        }
+    }
+
+    fn quoted(&mut self, start: usize) -> Result<String, String> {
+        //Opening quote already consumed
+        let mut out = String::new();
+        while let Some((_, c)) = self.chars.next() {
+            match c {
+                '"' => return Ok(out),
+                '\\' => match self.chars.next() {
+                    Some((_, escaped)) => out.push(escaped),
+                    None => break,
+                },
+                _ => out.push(c),
+            }
+        }
+        Err(format!("unterminated quote at {}", start))
+    }
+
+    //Unquoted text runs until whitespace or, for a field name, an operator
+    fn word(&mut self, stop_at_op: bool) -> String {
+        let mut out = String::new();
+        while let Some((_, c)) = self.chars.peek() {
+            if c.is_whitespace() || (stop_at_op && is_op_char(*c)) {
+                break;
+            }
+            out.push(*c);
+            self.chars.next();
+        }
+        out
+    }
+
+    fn op(&mut self) -> Option<Op> {
+        let (_, c) = *self.chars.peek()?;
+        let op = match c {
+            ':' | '=' => Op::Eq,
+            '~' => Op::Contains,
+            '<' => Op::Lt,
+            '>' => Op::Gt,
+            _ => return None,
+        };
+        self.chars.next();
+        if let Some((_, '=')) = self.chars.peek() {
+            match op {
+                Op::Lt => {
+                    self.chars.next();
+                    return Some(Op::Le);
+                }
+                Op::Gt => {
+                    self.chars.next();
+                    return Some(Op::Ge);
+                }
+                _ => {}
+            }
+        }
+        Some(op)
+    }
+
+    fn value(&mut self) -> Result<String, String> {
+        match self.chars.peek() {
+            Some((i, '"')) => {
+                let start = *i;
+                self.chars.next();
+                self.quoted(start)
+            }
+            _ => Ok(self.word(false)),
+        }
+    }
+
+    fn condition(&mut self) -> Result<Option<Condition>, String> {
+        self.skip_whitespace();
+        let (start, first) = match self.chars.peek() {
+            Some((i, c)) => (*i, *c),
+            None => return Ok(None),
+        };
+        let negated = first == '-';
+        if negated {
+            self.chars.next();
+        }
+        let head = match self.chars.peek() {
+            Some((i, '"')) => {
+                let quote_start = *i;
+                self.chars.next();
+                self.quoted(quote_start)?
+            }
+            Some((_, c)) if !c.is_whitespace() => self.word(true),
+            _ => return Err(format!("nothing to negate at {}", start)),
+        };
+        match self.op() {
+            Some(op) => {
+                if head.is_empty() {
+                    return Err(format!("missing field name at {}", start));
+                }
+                let value = self.value()?;
+                if value.is_empty() {
+                    return Err(format!("missing value for '{}'", head));
+                }
+                Ok(Some(Condition {
+                    negated,
+                    field: Some(head.to_lowercase()),
+                    op,
+                    value,
+                }))
+            }
+            None => Ok(Some(Condition {
+                negated,
+                field: None,
+                op: Op::Contains,
+                value: head,
+            })),
+        }
+    }
+}
+
+fn is_op_char(c: char) -> bool {
+    matches!(c, ':' | '=' | '~' | '<' | '>')
+}
+
+pub fn parse(input: &str) -> Result<Filter, String> {
+    let mut parser = Parser {
+        chars: input.char_indices().peekable(),
+    };
+    let mut conditions = Vec::new();
+    while let Some(condition) = parser.condition()? {
+        conditions.push(condition);
+    }
+    Ok(Filter { conditions })
+}
+
+impl Filter {
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+
+    //Splits off the plain search words so they can keep the ranked name search
+    pub fn split_words(self) -> (Vec<String>, Filter) {
+        let (words, rest): (Vec<Condition>, Vec<Condition>) = self
+            .conditions
+            .into_iter()
+            .partition(|c| c.field.is_none() && !c.negated);
+        (
+            words.into_iter().map(|c| c.value).collect(),
+            Filter { conditions: rest },
+        )
+    }
+
+    pub fn matches(&self, item: &objects::Item, ctx: &Context) -> bool {
+        self.conditions.iter().all(|c| c.matches(item, ctx) != c.negated)
+    }
+}
+
+//Independently toggled item filters, shown as chips in the help bar. They apply to one board's
+//items and are saved with its views.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FilterState {
+    pub assigned_to_me: bool,
+    pub creator_is_me: bool,
+    //Status labels, any of which may match
+    pub statuses: Vec<String>,
+    //Group ids, any of which may match. Items come from the whole board while it's set.
+    pub groups: Vec<String>,
+    //Search box text, in the filter language above. A saved view keeps it as its filter text.
+    #[serde(skip)]
+    pub query: String,
+    //Due on or before today plus this many days. -1 means overdue.
+    pub due_within: Option<i64>,
+}
+
+//Steps of the due date window, cycled in order
+pub const DUE_WINDOWS: [Option<i64>; 5] = [None, Some(-1), Some(0), Some(7), Some(30)];
+
+fn condition(field: &str, op: Op, value: &str) -> Condition {
+    Condition {
+        negated: false,
+        field: Some(field.to_string()),
+        op,
+        value: value.to_string(),
+    }
+}
+
+impl FilterState {
+    pub fn is_empty(&self) -> bool {
+        *self == FilterState::default()
+    }
+
+    pub fn toggle_status(&mut self, status: &str) {
+        toggle(&mut self.statuses, status);
+    }
+
+    pub fn toggle_group(&mut self, group_id: &str) {
+        toggle(&mut self.groups, group_id);
+    }
+
+    pub fn next_due_window(&mut self) {
+        let index = DUE_WINDOWS.iter().position(|w| *w == self.due_within).unwrap_or(0);
+        self.due_within = DUE_WINDOWS[(index + 1) % DUE_WINDOWS.len()];
+    }
+
+    //Everything except the text query, which is ranked separately
+    pub fn matches(&self, item: &objects::Item, ctx: &Context) -> bool {
+        if self.assigned_to_me && !condition("assignee", Op::Eq, "me").matches(item, ctx) {
+            return false;
+        }
+        if self.creator_is_me && !condition("creator", Op::Eq, "me").matches(item, ctx) {
+            return false;
+        }
+        if !self.statuses.is_empty()
+            && !self
+                .statuses
+                .iter()
+                .any(|status| condition("status", Op::Eq, status).matches(item, ctx))
+        {
+            return false;
+        }
+        if !self.groups.is_empty()
+            && !self.groups.contains(&item.group.id)
+            && !self.groups.contains(&item.group.title)
+        {
+            return false;
+        }
+        if let Some(days) = self.due_within {
+            if !condition("due", Op::Le, &format!("{}d", days)).matches(item, ctx) {
+                return false;
+            }
+        }
+        true
+    }
+
+    //Chip texts; groups are named by their titles in the given groups
+    pub fn chips(&self, groups: &[objects::Group]) -> Vec<String> {
+        let mut chips = Vec::new();
+        if self.assigned_to_me {
+            chips.push(String::from("assigned: me"));
+        }
+        if self.creator_is_me {
+            chips.push(String::from("creator: me"));
+        }
+        if !self.statuses.is_empty() {
+            chips.push(format!("status: {}", self.statuses.join(", ")));
+        }
+        if !self.groups.is_empty() {
+            let titles = self
+                .groups
+                .iter()
+                .map(|id| {
+                    groups
+                        .iter()
+                        .find(|g| g.id == *id)
+                        .map(|g| g.title.clone())
+                        .unwrap_or_else(|| id.clone())
+                })
+                .collect::<Vec<String>>();
+            chips.push(format!("group: {}", titles.join(", ")));
+        }
+        if let Some(days) = self.due_within {
+            chips.push(due_window_label(days));
+        }
+        if !self.query.is_empty() {
+            chips.push(format!("search: {}", self.query));
+        }
+        chips
+    }
+}
+
+pub fn due_window_label(days: i64) -> String {
+    match days {
+        -1 => String::from("overdue"),
+        0 => String::from("due today"),
+        n => format!("due within {}d", n),
+    }
+}
+
+fn toggle(values: &mut Vec<String>, value: &str) {
+    match values.iter().position(|v| v == value) {
+        Some(index) => {
+            values.remove(index);
+        }
+        None => values.push(value.to_string()),
+    }
+}
+
+const PEOPLE_FIELDS: [&str; 4] = ["assignee", "person", "people", "owner"];
+
+//Text of the first column matching the chosen id, or else the column type
+fn column_text(item: &objects::Item, column_id: &str, type_: &str) -> Option<String> {
+    item.column_values
+        .iter()
+        .find(|cv| if column_id.is_empty() { cv.type_ == type_ } else { cv.id == column_id })
+        .map(|cv| cv.text.clone())
+}
+
+impl Condition {
+    //Candidate texts for the field; the condition holds if any of them matches
+    fn values(&self, item: &objects::Item, ctx: &Context) -> Vec<String> {
+        let field = match &self.field {
+            Some(field) => field.as_str(),
+            None => return vec![item.name.clone()],
+        };
+        match field {
+            "name" => vec![item.name.clone()],
+            "group" => vec![item.group.title.clone()],
+            "updated" => vec![item.updated_at.chars().take(10).collect()],
+            "creator" => vec![item.creator.name.clone()],
+            "status" => column_text(item, &ctx.board_meta.status_column_id, "color")
+                .into_iter()
+                .collect(),
+            "due" | "date" => column_text(item, "", "date").into_iter().collect(),
+            "label" => item
+                .column_values
+                .iter()
+                .filter(|cv| cv.type_ == "color" || cv.type_ == "dropdown")
+                .map(|cv| cv.text.clone())
+                .collect(),
+            f if PEOPLE_FIELDS.contains(&f) => {
+                column_text(item, &ctx.board_meta.user_column_id, "multiple-person")
+                    .into_iter()
+                    .collect()
+            }
+            f => item
+                .column_values
+                .iter()
+                .filter(|cv| cv.id == f || cv.title.to_lowercase() == f)
+                .map(|cv| cv.text.clone())
+                .collect(),
+        }
+    }
+
+    fn is_people(&self) -> bool {
+        match &self.field {
+            Some(field) => PEOPLE_FIELDS.contains(&field.as_str()),
+            None => false,
+        }
+    }
+
+    pub fn matches(&self, item: &objects::Item, ctx: &Context) -> bool {
+        if self.field.as_deref() == Some("creator") && self.value.eq_ignore_ascii_case("me") {
+            return item.creator.id == ctx.current_user.id;
+        }
+        let value = if self.is_people() && self.value.eq_ignore_ascii_case("me") {
+            //Boards without a people column fall back to subscribers
+            if column_text(item, &ctx.board_meta.user_column_id, "multiple-person").is_none() {
+                return item.subscribers.iter().any(|sub| sub.id == ctx.current_user.id);
+            }
+            ctx.current_user.name.clone()
+        } else {
+            self.value.clone()
+        };
+        self.values(item, ctx)
+            .iter()
+            .any(|text| compare(text, self.op, &value, ctx.today))
+    }
+}
+
+fn compare(text: &str, op: Op, value: &str, today: i64) -> bool {
+    let text_lower = text.to_lowercase();
+    let value_lower = value.to_lowercase();
+    match op {
+        //Multi-value columns (people, dropdowns) are comma separated
+        Op::Eq => text_lower == value_lower || text_lower.split(", ").any(|part| part == value_lower),
+        Op::Contains => text_lower.contains(&value_lower),
+        _ => {
+            if text.is_empty() {
+                return false;
+            }
+            let ordering = match (parse_date(text), resolve_date(value, today)) {
+                (Some(x), Some(y)) => x.cmp(&y),
+                _ => match (text.parse::<f64>(), value.parse::<f64>()) {
+                    (Ok(x), Ok(y)) => match x.partial_cmp(&y) {
+                        Some(ordering) => ordering,
+                        None => return false,
+                    },
+                    _ => text_lower.cmp(&value_lower),
+                },
+            };
+            match op {
+                Op::Lt => ordering == std::cmp::Ordering::Less,
+                Op::Le => ordering != std::cmp::Ordering::Greater,
+                Op::Gt => ordering == std::cmp::Ordering::Greater,
+                Op::Ge => ordering != std::cmp::Ordering::Less,
+                _ => false,
+            }
+        }
+    }
+}
+
+//Days since the unix epoch for a civil date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+//Leading YYYY-MM-DD of a date or datetime
+pub fn parse_date(text: &str) -> Option<i64> {
+    let date = text.get(0..10)?;
+    let mut parts = date.split('-');
+    let year = parts.next()?.parse::<i64>().ok()?;
+    let month = parts.next()?.parse::<i64>().ok()?;
+    let day = parts.next()?.parse::<i64>().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+//Absolute dates, "today", or offsets from today such as 7d, 2w, -3d
+fn resolve_date(value: &str, today: i64) -> Option<i64> {
+    if value.eq_ignore_ascii_case("today") {
+        return Some(today);
+    }
+    if let Some(days) = parse_date(value) {
+        return Some(days);
+    }
+    if let Some(number) = value.strip_suffix('d') {
+        return number.parse::<i64>().ok().map(|count| today + count);
+    }
+    if let Some(number) = value.strip_suffix('w') {
+        return number.parse::<i64>().ok().map(|count| today + count * 7);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cond(negated: bool, field: Option<&str>, op: Op, value: &str) -> Condition {
+        Condition {
+            negated,
+            field: field.map(|f| f.to_string()),
+            op,
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_example_query() {
+        let filter = parse(r#"status:"Working on it" assignee:me due<7d -label:Done name~api"#).unwrap();
+        assert_eq!(
+            filter.conditions,
+            vec![
+                cond(false, Some("status"), Op::Eq, "Working on it"),
+                cond(false, Some("assignee"), Op::Eq, "me"),
+                cond(false, Some("due"), Op::Lt, "7d"),
+                cond(true, Some("label"), Op::Eq, "Done"),
+                cond(false, Some("name"), Op::Contains, "api"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_bare_words_and_negation() {
+        let filter = parse(r#"login -flaky "two words""#).unwrap();
+        assert_eq!(
+            filter.conditions,
+            vec![
+                cond(false, None, Op::Contains, "login"),
+                cond(true, None, Op::Contains, "flaky"),
+                cond(false, None, Op::Contains, "two words"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_comparison_operators() {
+        let filter = parse("estimate>=3 estimate<=8 due>today priority=High").unwrap();
+        let ops = filter.conditions.iter().map(|c| c.op).collect::<Vec<Op>>();
+        assert_eq!(ops, vec![Op::Ge, Op::Le, Op::Gt, Op::Eq]);
+    }
+
+    #[test]
+    fn parses_quoted_field_and_escapes() {
+        let filter = parse(r#""Due Date"<2021-09-01 name:"say \"hi\"""#).unwrap();
+        assert_eq!(
+            filter.conditions,
+            vec![
+                cond(false, Some("due date"), Op::Lt, "2021-09-01"),
+                cond(false, Some("name"), Op::Eq, r#"say "hi""#),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_input_is_empty_filter() {
+        assert!(parse("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(parse(r#"status:"Working"#).is_err());
+        assert!(parse("status:").is_err());
+        assert!(parse(":done").is_err());
+        assert!(parse("- foo").is_err());
+    }
+
+    #[test]
+    fn splits_plain_words() {
+        let (words, rest) = parse("api -done status:Stuck").unwrap().split_words();
+        assert_eq!(words, vec!["api".to_string()]);
+        assert_eq!(rest.conditions.len(), 2);
+    }
+
+    #[test]
+    fn resolves_relative_dates() {
+        let today = days_from_civil(2021, 8, 30);
+        assert_eq!(resolve_date("today", today), Some(today));
+        assert_eq!(resolve_date("7d", today), Some(today + 7));
+        assert_eq!(resolve_date("-1w", today), Some(today - 7));
+        assert_eq!(resolve_date("2021-09-06", today), Some(today + 7));
+        assert_eq!(resolve_date("soon", today), None);
+        assert_eq!(resolve_date("Ü", today), None);
+        assert_eq!(resolve_date("7ü", today), None);
+    }
+
+    #[test]
+    fn filter_state_toggles_and_cycles() {
+        let mut state = FilterState::default();
+        assert!(state.is_empty());
+        state.toggle_status("Done");
+        state.toggle_status("Stuck");
+        state.toggle_status("Done");
+        assert_eq!(state.statuses, vec!["Stuck".to_string()]);
+        state.next_due_window();
+        assert_eq!(state.due_within, Some(-1));
+        for _ in 1..DUE_WINDOWS.len() {
+            state.next_due_window();
+        }
+        assert_eq!(state.due_within, None);
+        assert_eq!(state.chips(&[]), vec!["status: Stuck".to_string()]);
+        state.toggle_group("planned");
+        let groups = vec![objects::Group { id: String::from("planned"), title: String::from("Planned"), color: String::new() }];
+        assert_eq!(state.chips(&groups)[1], "group: Planned");
+    }
+
+    #[test]
+    fn evaluates_against_column_values() {
+        let me = objects::User {
+            id: 1,
+            email: String::from(""),
+            name: String::from("Nate"),
+        };
+        let board_meta = cache::BoardMeta::new();
+        let ctx = Context {
+            current_user: &me,
+            board_meta: &board_meta,
+            today: days_from_civil(2021, 8, 30),
+        };
+        let column = |id: &str, type_: &str, text: &str| objects::ColumnValue {
+            additional_info: String::from(""),
+            id: id.to_string(),
+            text: text.to_string(),
+            title: id.to_string(),
+            type_: type_.to_string(),
+        };
+        let mut item = objects::Item::new();
+        item.name = String::from("Fix API auth");
+        item.column_values = vec![
+            column("status", "color", "Working on it"),
+            column("person", "multiple-person", "Nate, Sam"),
+            column("date4", "date", "2021-09-02"),
+        ];
+
+        let matches = |query: &str| parse(query).unwrap().matches(&item, &ctx);
+        assert!(matches(r#"status:"working on it" assignee:me due<7d name~api"#));
+        assert!(matches("-status:Done"));
+        assert!(!matches("due<1d"));
+        assert!(!matches("assignee:Alex"));
+        assert!(!matches("name>Ü"));
+    }
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::OpenOptions;
+use std::path::{Path, PathBuf};
+
+//Saving the API traffic under a directory, or answering requests from what was saved there
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mode {
+    Record(PathBuf),
+    Replay(PathBuf),
+}
+
+//A request body sent to the API and the response it got
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Exchange {
+    pub request: Value,
+    pub response: Value,
+}
+
+//One file per GraphQL operation; documents built at runtime have no name and share raw.json
+pub fn fixture_path(dir: &Path, request: &Value) -> PathBuf {
+    let name = request
+        .get("operationName")
+        .and_then(|name| name.as_str())
+        .unwrap_or("raw");
+    dir.join(format!("{}.json", name))
+}
+
+pub fn read(path: &Path) -> Vec<Exchange> {
+    if !path.exists() {
+        return Vec::new();
+    }
+    let data = std::fs::read_to_string(path).expect("Unable to read fixture file");
+    serde_json::from_str(&data).expect("Unable to parse fixture file")
+}
+
+//Adds an exchange to its fixture file, replacing an earlier one for the same request
+pub fn save(dir: &Path, exchange: Exchange) -> Result<(), std::io::Error> {
+    std::fs::create_dir_all(dir)?;
+    let path = fixture_path(dir, &exchange.request);
+    let mut exchanges = read(&path);
+    exchanges.retain(|e| e.request != exchange.request);
+    exchanges.push(exchange);
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+    serde_json::to_writer_pretty(file, &exchanges).expect("could not write to file");
+    Ok(())
+}
+
+//Saved response to a request, matched on the whole body: query, operation name and variables
+pub fn find(dir: &Path, request: &Value) -> Option<Value> {
+    read(&fixture_path(dir, request))
+        .into_iter()
+        .find(|exchange| exchange.request == *request)
+        .map(|exchange| exchange.response)
+}
+
+//Answers a request from the fixtures under dir. A request without one panics rather than
+//reaching the API, so a replayed run never depends on the network.
+pub fn answer(dir: &Path, request: &Value) -> Value {
+    match find(dir, request) {
+        Some(response) => response,
+        None => panic!(
+            "no fixture in {} for request {}",
+            fixture_path(dir, request).display(),
+            request
+        ),
+    }
+}
+
+//MONDAY_FIXTURES=record:<dir> saves API traffic as fixtures, replay:<dir> serves it back
+pub fn from_env() -> Result<Option<Mode>, String> {
+    let setting = match std::env::var("MONDAY_FIXTURES") {
+        Ok(setting) => setting,
+        Err(_) => return Ok(None),
+    };
+    match setting.split_once(':') {
+        Some(("record", dir)) => Ok(Some(Mode::Record(PathBuf::from(dir)))),
+        Some(("replay", dir)) => Ok(Some(Mode::Replay(PathBuf::from(dir)))),
+        _ => Err(format!(
+            "MONDAY_FIXTURES must be record:<dir> or replay:<dir>, not {}",
+            setting
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn saved_exchanges_are_found_by_request() {
+        let dir = std::env::temp_dir().join(format!("monday-fixtures-{}", std::process::id()));
+        let request = |id: i64| json!({ "operationName": "ItemDetail", "query": "query ItemDetail", "variables": { "item_id": id } });
+        save(&dir, Exchange { request: request(1), response: json!({ "data": 1 }) }).unwrap();
+        save(&dir, Exchange { request: request(2), response: json!({ "data": 2 }) }).unwrap();
+        //Recording the same request again replaces its response
+        save(&dir, Exchange { request: request(1), response: json!({ "data": 3 }) }).unwrap();
+        save(&dir, Exchange { request: json!({ "query": "query { boards { id } }" }), response: json!({}) }).unwrap();
+
+        assert_eq!(find(&dir, &request(1)), Some(json!({ "data": 3 })));
+        assert_eq!(find(&dir, &request(2)), Some(json!({ "data": 2 })));
+        assert_eq!(find(&dir, &request(3)), None);
+        assert_eq!(read(&dir.join("ItemDetail.json")).len(), 2);
+        assert_eq!(read(&dir.join("raw.json")).len(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
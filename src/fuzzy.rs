@@ -0,0 +1,136 @@
+//fzf-style fuzzy matching. Every query character has to appear in order, and matches
+//score higher when they're consecutive or start a word. Whitespace separated terms are
+//matched independently, so they can appear in any order.
+
+const SCORE_MATCH: i64 = 16;
+const PENALTY_GAP_START: i64 = 3;
+const PENALTY_GAP_EXTENSION: i64 = 1;
+const BONUS_BOUNDARY: i64 = 8;
+const BONUS_CAMEL: i64 = 7;
+const BONUS_CONSECUTIVE: i64 = 4;
+const BONUS_FIRST_CHAR: i64 = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    //Char indices of the matched characters, ascending
+    pub positions: Vec<usize>,
+}
+
+fn bonus(text: &[char], index: usize) -> i64 {
+    if index == 0 {
+        return BONUS_BOUNDARY;
+    }
+    let (prev, cur) = (text[index - 1], text[index]);
+    if !prev.is_alphanumeric() && cur.is_alphanumeric() {
+        BONUS_BOUNDARY
+    } else if prev.is_lowercase() && cur.is_uppercase() || !prev.is_numeric() && cur.is_numeric() {
+        BONUS_CAMEL
+    } else {
+        0
+    }
+}
+
+fn chars_eq(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+//Best scoring alignment of one term, or None if it isn't a subsequence of the text
+fn match_term(term: &[char], text: &[char]) -> Option<FuzzyMatch> {
+    let (m, n) = (term.len(), text.len());
+    if m == 0 {
+        return Some(FuzzyMatch { score: 0, positions: Vec::new() });
+    }
+    if m > n {
+        return None;
+    }
+    //score[i][j]: best score with term[i] matched at text[j]; from[i][j]: where term[i - 1] matched
+    let mut score = vec![vec![None; n]; m];
+    let mut from = vec![vec![0; n]; m];
+    for i in 0..m {
+        //Best predecessor at least one character back, already charged for the gap up to j
+        let mut best: Option<(i64, usize)> = None;
+        for j in i..n {
+            if i > 0 && j >= 2 {
+                let extended = best.map(|(s, k)| (s - PENALTY_GAP_EXTENSION, k));
+                let opened = score[i - 1][j - 2].map(|prev| (prev - PENALTY_GAP_START, j - 2));
+                best = match (extended, opened) {
+                    (Some(e), Some(o)) => Some(if e.0 > o.0 { e } else { o }),
+                    (e, o) => e.or(o),
+                };
+            }
+            if !chars_eq(term[i], text[j]) {
+                continue;
+            }
+            let here = SCORE_MATCH + bonus(text, j);
+            if i == 0 {
+                let first = if j == 0 { BONUS_FIRST_CHAR } else { 0 };
+                score[i][j] = Some(here + first);
+                continue;
+            }
+            let consecutive = score[i - 1][j - 1].map(|prev| (prev + here + BONUS_CONSECUTIVE, j - 1));
+            let gapped = best.map(|(s, k)| (s + here, k));
+            let chosen = match (consecutive, gapped) {
+                (Some(c), Some(g)) => Some(if c.0 >= g.0 { c } else { g }),
+                (c, g) => c.or(g),
+            };
+            if let Some((s, k)) = chosen {
+                score[i][j] = Some(s);
+                from[i][j] = k;
+            }
+        }
+    }
+    let (best_score, mut j) = (0..n)
+        .filter_map(|j| score[m - 1][j].map(|s| (s, j)))
+        .max_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)))?;
+    let mut positions = vec![0; m];
+    for i in (0..m).rev() {
+        positions[i] = j;
+        j = from[i][j];
+    }
+    Some(FuzzyMatch { score: best_score, positions })
+}
+
+//Scores a whole query; every term has to match
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let text = text.chars().collect::<Vec<char>>();
+    let mut total = FuzzyMatch { score: 0, positions: Vec::new() };
+    for term in query.split_whitespace() {
+        let found = match_term(&term.chars().collect::<Vec<char>>(), &text)?;
+        total.score += found.score;
+        total.positions.extend(found.positions);
+    }
+    total.positions.sort_unstable();
+    total.positions.dedup();
+    Some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_subsequences_only() {
+        assert!(fuzzy_match("rdmp", "Roadmap").is_some());
+        assert!(fuzzy_match("pmdr", "Roadmap").is_none());
+        assert_eq!(fuzzy_match("", "Roadmap").map(|m| m.score), Some(0));
+    }
+
+    #[test]
+    fn terms_match_in_any_order() {
+        let found = fuzzy_match("bug login", "Login page bug").unwrap();
+        assert_eq!(found.positions, vec![0, 1, 2, 3, 4, 11, 12, 13]);
+    }
+
+    #[test]
+    fn prefers_consecutive_and_word_start_matches() {
+        let exact = fuzzy_match("api", "Fix api timeout").unwrap();
+        let scattered = fuzzy_match("api", "Add pagination").unwrap();
+        assert!(exact.score > scattered.score);
+        assert_eq!(exact.positions, vec![4, 5, 6]);
+
+        let boundary = fuzzy_match("sb", "Sprint Board").unwrap();
+        let inner = fuzzy_match("sb", "Usability").unwrap();
+        assert!(boundary.score > inner.score);
+    }
+}
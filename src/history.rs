@@ -0,0 +1,198 @@
+use super::app;
+use super::objects;
+use super::views::MenuItem;
+
+//Back/forward navigation between places: the lists and details a user lands on, as opposed to
+//menus and input forms. A place is remembered with its selection and search text.
+
+const MAX_HISTORY: usize = 50;
+
+#[derive(Debug, Clone)]
+pub struct Location {
+    pub menu_item: MenuItem,
+    pub selected: Option<usize>,
+    pub key_input: Vec<char>,
+    pub board: objects::Board,
+    pub group: objects::Group,
+    pub item_id: String,
+}
+
+impl Location {
+    //Same view showing the same board, group or item; selection and search may differ
+    pub fn same_place(&self, other: &Location) -> bool {
+        self.menu_item == other.menu_item
+            && self.board.id == other.board.id
+            && self.group.id == other.group.id
+            && self.item_id == other.item_id
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    back: Vec<Location>,
+    forward: Vec<Location>,
+    //Latest state of the place being shown, or last shown before a menu was opened
+    pub current: Option<Location>,
+}
+
+impl History {
+    //A new place was visited, so whatever was ahead is dropped
+    pub fn record(&mut self, location: Location) {
+        self.back.push(location);
+        if self.back.len() > MAX_HISTORY {
+            self.back.remove(0);
+        }
+        self.forward = Vec::new();
+    }
+
+    pub fn back(&mut self, current: Location) -> Option<Location> {
+        let previous = self.back.pop()?;
+        self.forward.push(current);
+        Some(previous)
+    }
+
+    pub fn forward(&mut self, current: Location) -> Option<Location> {
+        let next = self.forward.pop()?;
+        self.back.push(current);
+        Some(next)
+    }
+}
+
+pub fn is_place(menu_item: MenuItem) -> bool {
+    matches!(
+        menu_item,
+        MenuItem::Home
+            | MenuItem::Boards
+            | MenuItem::Groups
+            | MenuItem::Items
+            | MenuItem::ItemDetail
+            | MenuItem::FindItem
+            | MenuItem::IndexSearch
+    )
+}
+
+pub fn location(app: &app::App) -> Location {
+    let menu_item = app.active_menu_item;
+    let shows_group = matches!(menu_item, MenuItem::Items | MenuItem::ItemDetail);
+    Location {
+        menu_item,
+        selected: app.list_state.selected(),
+        key_input: app.key_input.clone(),
+        board: if shows_group || menu_item == MenuItem::Groups {
+            app.board_detail.clone()
+        } else {
+            objects::Board::new()
+        },
+        group: if shows_group { app.group_detail.clone() } else { objects::Group::new() },
+        item_id: match menu_item {
+            MenuItem::ItemDetail => app.item_detail.id.clone(),
+            _ => String::new(),
+        },
+    }
+}
+
+//Keeps the current place up to date and records it once a different place is shown
+pub fn track(app: &mut app::App) {
+    if !is_place(app.active_menu_item) {
+        return;
+    }
+    let here = location(app);
+    if let Some(previous) = app.history.current.take() {
+        if !previous.same_place(&here) {
+            app.history.record(previous);
+        }
+    }
+    app.history.current = Some(here);
+}
+
+//Shows a location again, reloading its groups, items or item only when they've been replaced since
+pub fn restore(app: &mut app::App, location: Location) {
+    let board_changed = !location.board.id.is_empty() && app.board_detail.id != location.board.id;
+    if board_changed {
+        app.set_board(location.board.clone());
+        app.groups = app.api.group_list(location.board.id.clone());
+    }
+    if !location.group.id.is_empty() && (board_changed || app.group_detail.id != location.group.id) {
+        app.group_detail = location.group.clone();
+        app.fetch_items(location.board.id.clone(), location.group.id.clone());
+    }
+    if !location.item_id.is_empty() && app.item_detail.id != location.item_id {
+        app.fetch_item_detail(location.item_id.clone());
+    }
+    app.key_input = location.key_input.clone();
+    app.list_state.select(location.selected);
+    app.active_menu_item = location.menu_item;
+    app.history.current = Some(location);
+}
+
+//From a menu, back returns to the place it was opened from
+pub fn go_back(app: &mut app::App) {
+    if !is_place(app.active_menu_item) {
+        if let Some(current) = app.history.current.clone() {
+            restore(app, current);
+        }
+        return;
+    }
+    if let Some(previous) = app.history.back(location(app)) {
+        restore(app, previous);
+    }
+}
+
+pub fn go_forward(app: &mut app::App) {
+    if !is_place(app.active_menu_item) {
+        return;
+    }
+    if let Some(next) = app.history.forward(location(app)) {
+        restore(app, next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(menu_item: MenuItem, group_id: &str) -> Location {
+        let mut group = objects::Group::new();
+        group.id = group_id.to_string();
+        Location {
+            menu_item,
+            selected: Some(0),
+            key_input: Vec::new(),
+            board: objects::Board::new(),
+            group,
+            item_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn back_and_forward_walk_the_stack() {
+        let mut history = History::default();
+        history.record(at(MenuItem::Boards, ""));
+        history.record(at(MenuItem::Items, "a"));
+        let back = history.back(at(MenuItem::Items, "b")).unwrap();
+        assert!(back.same_place(&at(MenuItem::Items, "a")));
+        let forward = history.forward(back).unwrap();
+        assert!(forward.same_place(&at(MenuItem::Items, "b")));
+
+        //Visiting somewhere new drops the forward stack
+        history.back(forward).unwrap();
+        history.record(at(MenuItem::Home, ""));
+        assert!(history.forward(at(MenuItem::Groups, "")).is_none());
+    }
+
+    #[test]
+    fn history_is_capped() {
+        let mut history = History::default();
+        for i in 0..MAX_HISTORY + 5 {
+            history.record(at(MenuItem::Items, &i.to_string()));
+        }
+        let mut steps = 0;
+        let mut current = at(MenuItem::Home, "");
+        while let Some(previous) = history.back(current.clone()) {
+            current = previous;
+            steps += 1;
+        }
+        assert_eq!(steps, MAX_HISTORY);
+        assert!(current.same_place(&at(MenuItem::Items, "5")));
+    }
+}
@@ -0,0 +1,297 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::OpenOptions;
+use super::cache;
+use super::objects;
+
+//Local full-text index over every item seen so far, stored in ~/.monday/index.json.
+//Only the documents are saved; the term postings are rebuilt when the index is read.
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IndexedItem {
+    pub id: String,
+    pub name: String,
+    pub board_id: String,
+    pub board_name: String,
+    pub group_id: String,
+    pub group_title: String,
+    //Column texts, space separated
+    pub columns: String,
+    //Update and reply bodies. Kept when an item is re-indexed from a list without updates.
+    pub updates: String,
+}
+
+impl IndexedItem {
+    //Item with just enough set to open it from a search result
+    pub fn to_item(&self) -> objects::Item {
+        let mut item = objects::Item::new();
+        item.id = self.id.clone();
+        item.name = self.name.clone();
+        item.board = objects::Board {
+            id: self.board_id.clone(),
+            name: self.board_name.clone(),
+        };
+        item.group.id = self.group_id.clone();
+        item.group.title = self.group_title.clone();
+        item
+    }
+
+    fn terms(&self) -> BTreeSet<String> {
+        [&self.name, &self.columns, &self.updates]
+            .iter()
+            .flat_map(|text| tokenize(text))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    items: HashMap<String, IndexedItem>,
+    #[serde(skip)]
+    postings: BTreeMap<String, BTreeSet<String>>,
+}
+
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect()
+}
+
+impl SearchIndex {
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn rebuild_postings(&mut self) {
+        self.postings = BTreeMap::new();
+        let items = self.items.values().cloned().collect::<Vec<IndexedItem>>();
+        for item in items.iter() {
+            self.add_postings(item);
+        }
+    }
+
+    fn add_postings(&mut self, item: &IndexedItem) {
+        for term in item.terms() {
+            self.postings.entry(term).or_default().insert(item.id.clone());
+        }
+    }
+
+    fn remove_postings(&mut self, item: &IndexedItem) {
+        for term in item.terms() {
+            if let Some(ids) = self.postings.get_mut(&term) {
+                ids.remove(&item.id);
+                if ids.is_empty() {
+                    self.postings.remove(&term);
+                }
+            }
+        }
+    }
+
+    //Adds or refreshes an item. The board is used when the item doesn't carry its own.
+    pub fn add_item(&mut self, item: &objects::Item, board: &objects::Board) {
+        let previous = self.items.get(&item.id).cloned();
+        let board = if item.board.id.is_empty() { board } else { &item.board };
+        let updates = if item.updates.is_empty() {
+            previous.as_ref().map(|p| p.updates.clone()).unwrap_or_default()
+        } else {
+            item.updates
+                .iter()
+                .flat_map(|update| {
+                    std::iter::once(update.text_body.clone())
+                        .chain(update.replies.iter().map(|reply| reply.text_body.clone()))
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        //Item details don't include the group, so keep the one seen in a list
+        let (group_id, group_title) = match (&previous, item.group.id.is_empty()) {
+            (Some(p), true) => (p.group_id.clone(), p.group_title.clone()),
+            _ => (item.group.id.clone(), item.group.title.clone()),
+        };
+        let indexed = IndexedItem {
+            id: item.id.clone(),
+            name: item.name.clone(),
+            board_id: board.id.clone(),
+            board_name: board.name.clone(),
+            group_id,
+            group_title,
+            columns: item
+                .column_values
+                .iter()
+                .map(|cv| cv.text.clone())
+                .collect::<Vec<String>>()
+                .join(" "),
+            updates,
+        };
+        if let Some(previous) = previous {
+            self.remove_postings(&previous);
+        }
+        self.add_postings(&indexed);
+        self.items.insert(indexed.id.clone(), indexed);
+    }
+
+    pub fn add_items(&mut self, items: &[objects::Item], board: &objects::Board) {
+        for item in items {
+            self.add_item(item, board);
+        }
+    }
+
+    //Indexes a fresh list of the board's items, or of one of its groups, and drops the entries
+    //from there that the list no longer has, e.g. items deleted, archived or moved elsewhere
+    pub fn replace_items(&mut self, items: &[objects::Item], board: &objects::Board, group_id: Option<&str>) {
+        let gone = self
+            .items
+            .values()
+            .filter(|indexed| {
+                indexed.board_id == board.id
+                    && group_id.is_none_or(|group_id| indexed.group_id == group_id)
+                    && !items.iter().any(|item| item.id == indexed.id)
+            })
+            .cloned()
+            .collect::<Vec<IndexedItem>>();
+        for indexed in gone {
+            self.remove_postings(&indexed);
+            self.items.remove(&indexed.id);
+        }
+        self.add_items(items, board);
+    }
+
+    //Items containing every query term. The last term also matches as a prefix so
+    //results show up while it's still being typed. Name hits rank first.
+    pub fn search(&self, query: &str) -> Vec<&IndexedItem> {
+        let terms = tokenize(query);
+        let last = match terms.last() {
+            Some(last) => last,
+            None => return Vec::new(),
+        };
+        let mut candidates: Option<BTreeSet<String>> = None;
+        for (i, term) in terms.iter().enumerate() {
+            let ids = if i + 1 == terms.len() {
+                self.postings
+                    .range(term.clone()..)
+                    .take_while(|(key, _)| key.starts_with(last.as_str()))
+                    .flat_map(|(_, ids)| ids.iter().cloned())
+                    .collect::<BTreeSet<String>>()
+            } else {
+                self.postings.get(term).cloned().unwrap_or_default()
+            };
+            candidates = Some(match candidates {
+                Some(found) => found.intersection(&ids).cloned().collect(),
+                None => ids,
+            });
+        }
+        let mut results = candidates
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|id| self.items.get(id))
+            .collect::<Vec<&IndexedItem>>();
+        let name_hits = |item: &IndexedItem| {
+            let words = tokenize(&item.name);
+            terms
+                .iter()
+                .filter(|term| words.iter().any(|word| word.starts_with(term.as_str())))
+                .count()
+        };
+        results.sort_by(|a, b| name_hits(b).cmp(&name_hits(a)).then_with(|| a.name.cmp(&b.name)));
+        results
+    }
+}
+
+pub fn exists() -> bool {
+    std::path::Path::new(&get_index_path()).exists()
+}
+
+pub fn read() -> SearchIndex {
+    if !exists() {
+        return SearchIndex::default();
+    }
+    let data = std::fs::read_to_string(get_index_path()).expect("Unable to read file");
+    //An unreadable index is rebuilt from scratch as items are fetched again
+    let mut index: SearchIndex = serde_json::from_str(&data).unwrap_or_default();
+    index.rebuild_postings();
+    index
+}
+
+pub fn write(dir: &str, index: &SearchIndex) -> Result<(), std::io::Error> {
+    std::fs::create_dir_all(dir)?;
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(index_path(dir))?;
+    serde_json::to_writer(file, index).expect("could not write to file");
+    Ok(())
+}
+
+pub fn get_index_path() -> String {
+    index_path(&cache::get_cache_dir())
+}
+
+pub fn index_path(dir: &str) -> String {
+    format!("{}/{}", dir, "index.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, name: &str, column: &str) -> objects::Item {
+        let mut item = objects::Item::new();
+        item.id = id.to_string();
+        item.name = name.to_string();
+        item.column_values.push(objects::ColumnValue {
+            additional_info: String::new(),
+            id: String::from("status"),
+            text: column.to_string(),
+            title: String::from("Status"),
+            type_: String::from("color"),
+        });
+        item
+    }
+
+    #[test]
+    fn searches_terms_and_prefixes() {
+        let mut index = SearchIndex::default();
+        let board = objects::Board::new();
+        index.add_items(&[item("1", "Login page", "Stuck"), item("2", "Stuck deploy", "Done")], &board);
+        let ids = |results: Vec<&IndexedItem>| results.iter().map(|i| i.id.clone()).collect::<Vec<String>>();
+        assert_eq!(ids(index.search("stuck")), vec!["2", "1"]);
+        assert_eq!(ids(index.search("log")), vec!["1"]);
+        assert_eq!(ids(index.search("login done")), Vec::<String>::new());
+    }
+
+    #[test]
+    fn reindexing_replaces_old_terms() {
+        let mut index = SearchIndex::default();
+        let board = objects::Board::new();
+        index.add_item(&item("1", "Old name", ""), &board);
+        index.add_item(&item("1", "New name", ""), &board);
+        assert!(index.search("old").is_empty());
+        assert_eq!(index.search("new").len(), 1);
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn refetching_a_group_drops_its_missing_items() {
+        let mut index = SearchIndex::default();
+        let board = objects::Board { id: String::from("1"), name: String::from("Roadmap") };
+        let in_group = |id: &str, name: &str, group: &str| {
+            let mut item = item(id, name, "");
+            item.group.id = group.to_string();
+            item
+        };
+        index.add_items(&[in_group("1", "Login page", "a"), in_group("2", "Deploy", "a"), in_group("3", "Docs", "b")], &board);
+        index.replace_items(&[in_group("1", "Login page", "a")], &board, Some("a"));
+        assert!(index.search("deploy").is_empty());
+        assert_eq!(index.search("docs").len(), 1);
+        //The whole board
+        index.replace_items(&[in_group("1", "Login page", "a")], &board, None);
+        assert!(index.search("docs").is_empty());
+        assert_eq!(index.len(), 1);
+    }
+}
@@ -0,0 +1,473 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use super::app::InputMode;
+use super::cache;
+
+//Named actions bound to keys, read from ~/.monday/keymap.json. Pressed keys are translated
+//to the key the view already handles, so views keep matching on their built-in keys.
+//
+//{
+//  "preset": "vim",
+//  "global": { "quit": ["q", "Ctrl-c"] },
+//  "views": { "Items": { "create_item": ["n"] } }
+//}
+//
+//A binding listed in the file replaces the preset's keys for that action.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Action {
+    pub name: &'static str,
+    //Key the view handles for this action. Empty for actions handled before the view.
+    pub key: &'static str,
+    pub description: &'static str,
+}
+
+const fn action(name: &'static str, key: &'static str, description: &'static str) -> Action {
+    Action { name, key, description }
+}
+
+pub const QUIT: &str = "quit";
+pub const QUICK_SWITCH: &str = "quick_switch";
+pub const HELP: &str = "help";
+pub const SEARCH: &str = "search";
+pub const HISTORY_BACK: &str = "history_back";
+pub const HISTORY_FORWARD: &str = "history_forward";
+
+pub const GLOBAL_ACTIONS: &[Action] = &[
+    action("up", "Up", "Move Up"),
+    action("down", "Down", "Move Down"),
+    action("back", "Left", "Back"),
+    action("open", "Right", "Open"),
+    action("select", "Enter", "Select"),
+    action(SEARCH, "", "Search"),
+    action(HISTORY_BACK, "", "History Back"),
+    action(HISTORY_FORWARD, "", "History Forward"),
+    action(QUICK_SWITCH, "", "Quick Switch"),
+    action(HELP, "", "Key Bindings"),
+    action(QUIT, "", "Quit"),
+];
+
+const HOME_ACTIONS: &[Action] = &[
+    action("group_by", "F2", "Group by Status / Due Date"),
+    action("refresh", "F5", "Refresh"),
+];
+
+const BOARDS_ACTIONS: &[Action] = &[
+    action("create_board", "F3", "Create Board"),
+    action("board_options", "F4", "Board Options"),
+    action("find_item", "F5", "Find Item"),
+    action("search_index", "F6", "Search Index"),
+    action("star_board", "F7", "Star Board"),
+];
+
+const BOARD_SETTINGS_ACTIONS: &[Action] = &[
+    action("wider", "+", "Wider Column"),
+    action("narrower", "-", "Narrower Column"),
+];
+
+const NEW_BOARD_ACTIONS: &[Action] = &[
+    action("board_kind", "Tab", "Board Kind"),
+    action("board_workspace", "F2", "Workspace"),
+];
+
+const GROUPS_ACTIONS: &[Action] = &[
+    action("create_group", "F3", "Create Group"),
+    action("group_options", "F4", "Group Options"),
+];
+
+const ITEMS_ACTIONS: &[Action] = &[
+    action("clear_filters", "F1", "Clear Filters"),
+    action("assigned_to_me", "F2", "Assigned to Me"),
+    action("create_item", "F3", "Create Item"),
+    action("filters", "F4", "Filters"),
+    action("toggle_select", "Space", "Select"),
+    action("select_all", "F5", "Select All"),
+    action("bulk_actions", "F6", "Bulk Actions"),
+    action("table_view", "F7", "Table View"),
+    action("sort", "F8", "Sort"),
+    action("reverse_sort", "F9", "Reverse Sort"),
+    action("views", "F10", "Views"),
+    action("group_filter", "F11", "Filter Group"),
+];
+
+const ITEM_TABLE_ACTIONS: &[Action] = &[
+    action("scope", "F2", "Group/Board"),
+    action("scroll", "Tab", "Scroll Columns"),
+    action("wider", "+", "Wider Column"),
+    action("narrower", "-", "Narrower Column"),
+    action("hide_column", "F4", "Hide Column"),
+    action("reset_columns", "F5", "Reset Columns"),
+    action("pick_columns", "F6", "Pick Columns"),
+];
+
+const FILTER_OPTIONS_ACTIONS: &[Action] = &[
+    action("clear_filters", "F1", "Clear Filters"),
+];
+
+const VIEW_OPTIONS_ACTIONS: &[Action] = &[
+    action("toggle_default", "F2", "Toggle Default"),
+    action("remove_view", "Delete", "Remove View"),
+];
+
+const ITEM_OPTIONS_ACTIONS: &[Action] = &[
+    action("post_update", "U", "Post Update"),
+];
+
+const STATUS_OPTIONS_ACTIONS: &[Action] = &[
+    action("next_column", "Tab", "Next Status Column"),
+];
+
+//Actions specific to a view, by MenuItem name
+pub fn view_actions(view: &str) -> &'static [Action] {
+    match view {
+        "Home" => HOME_ACTIONS,
+        "Boards" => BOARDS_ACTIONS,
+        "BoardSettings" => BOARD_SETTINGS_ACTIONS,
+        "NewBoard" => NEW_BOARD_ACTIONS,
+        "Groups" => GROUPS_ACTIONS,
+        "Items" => ITEMS_ACTIONS,
+        "ItemTable" => ITEM_TABLE_ACTIONS,
+        "FilterOptions" => FILTER_OPTIONS_ACTIONS,
+        "ViewOptions" => VIEW_OPTIONS_ACTIONS,
+        "ItemOptions" => ITEM_OPTIONS_ACTIONS,
+        "StatusOptions" => STATUS_OPTIONS_ACTIONS,
+        _ => &[],
+    }
+}
+
+//List views with a search box
+pub fn is_searchable(view: &str) -> bool {
+    matches!(view, "Boards" | "Groups" | "GroupsForMove" | "Items")
+}
+
+//Views where letters are typed into a text field rather than used as commands
+pub fn is_text_entry(view: &str) -> bool {
+    matches!(
+        view,
+        "NewBoard"
+            | "DuplicateBoard"
+            | "NewGroup"
+            | "RenameGroup"
+            | "DuplicateGroup"
+            | "NewItem"
+            | "ItemUpdate"
+            | "SaveView"
+            | "FindItem"
+            | "IndexSearch"
+            | "QuickSwitch"
+    )
+}
+
+//Key names as written in the keymap file, e.g. "j", "Enter", "F5", "Ctrl-p", "Alt-Left", "Space"
+pub fn parse_key(key: &str) -> Result<KeyEvent, String> {
+    let (modifiers, name) = match (key.strip_prefix("Ctrl-"), key.strip_prefix("Alt-")) {
+        (Some(rest), _) if !rest.is_empty() => (KeyModifiers::CONTROL, rest),
+        (_, Some(rest)) if !rest.is_empty() => (KeyModifiers::ALT, rest),
+        _ => (KeyModifiers::NONE, key),
+    };
+    let code = match name {
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" => KeyCode::Tab,
+        "Backspace" => KeyCode::Backspace,
+        "Delete" => KeyCode::Delete,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Space" => KeyCode::Char(' '),
+        _ => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                (Some('F'), Some(_)) => match name[1..].parse::<u8>() {
+                    Ok(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                    _ => return Err(format!("unknown key \"{}\"", key)),
+                },
+                _ => return Err(format!("unknown key \"{}\"", key)),
+            }
+        }
+    };
+    Ok(KeyEvent { code, modifiers })
+}
+
+//Shift is part of the character itself, so it's ignored when comparing characters
+fn same_key(a: &KeyEvent, b: &KeyEvent) -> bool {
+    let modifiers = |key: &KeyEvent| match key.code {
+        KeyCode::Char(_) => key.modifiers - KeyModifiers::SHIFT,
+        _ => key.modifiers,
+    };
+    a.code == b.code && modifiers(a) == modifiers(b)
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct KeymapFile {
+    #[serde(default)]
+    pub preset: String,
+    #[serde(default)]
+    pub global: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub views: HashMap<String, HashMap<String, Vec<String>>>,
+}
+
+#[derive(Debug, Clone)]
+struct Binding {
+    key: KeyEvent,
+    label: String,
+    action: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    pub preset: String,
+    global: Vec<Binding>,
+    views: HashMap<String, Vec<Binding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Keymap {
+        Keymap::from_file(&KeymapFile::default()).expect("default keymap is valid")
+    }
+}
+
+//Preset keys for a global action
+fn preset_keys(preset: &str, action: &Action) -> Vec<&'static str> {
+    let vim = preset == "vim";
+    match action.name {
+        "up" if vim => vec!["Up", "k"],
+        "down" if vim => vec!["Down", "j"],
+        "back" if vim => vec!["Left", "h"],
+        "open" if vim => vec!["Right", "l"],
+        SEARCH if vim => vec!["/"],
+        SEARCH => vec![],
+        HISTORY_BACK if vim => vec!["Alt-Left", "H"],
+        HISTORY_BACK => vec!["Alt-Left"],
+        HISTORY_FORWARD if vim => vec!["Alt-Right", "L"],
+        HISTORY_FORWARD => vec!["Alt-Right"],
+        QUICK_SWITCH => vec!["Ctrl-p"],
+        HELP if vim => vec!["?", "F12"],
+        HELP => vec!["F12"],
+        //Esc leaves search instead
+        QUIT if vim => vec!["q"],
+        QUIT => vec!["Esc"],
+        _ => vec![action.key],
+    }
+}
+
+fn bind(
+    actions: &[Action],
+    preset: &str,
+    overrides: Option<&HashMap<String, Vec<String>>>,
+) -> Result<Vec<Binding>, String> {
+    let mut bindings = Vec::new();
+    for action in actions {
+        let keys = match overrides.and_then(|o| o.get(action.name)) {
+            Some(keys) => keys.clone(),
+            None => preset_keys(preset, action).iter().map(|k| k.to_string()).collect(),
+        };
+        for label in keys {
+            bindings.push(Binding {
+                key: parse_key(&label)?,
+                label,
+                action: action.name.to_string(),
+            });
+        }
+    }
+    Ok(bindings)
+}
+
+impl Keymap {
+    pub fn from_file(file: &KeymapFile) -> Result<Keymap, String> {
+        let preset = if file.preset.is_empty() { "default" } else { file.preset.as_str() };
+        if preset != "default" && preset != "vim" {
+            return Err(format!("unknown keymap preset \"{}\"", preset));
+        }
+        let known = |actions: &[Action], names: &HashMap<String, Vec<String>>| {
+            match names.keys().find(|name| !actions.iter().any(|a| a.name == name.as_str())) {
+                Some(name) => Err(format!("unknown keymap action \"{}\"", name)),
+                None => Ok(()),
+            }
+        };
+        known(GLOBAL_ACTIONS, &file.global)?;
+        let mut views = HashMap::new();
+        for (view, names) in file.views.iter() {
+            known(view_actions(view), names)?;
+        }
+        for view in super::views::MenuItem::names() {
+            views.insert(view.to_string(), bind(view_actions(view), preset, file.views.get(*view))?);
+        }
+        Ok(Keymap {
+            preset: preset.to_string(),
+            global: bind(GLOBAL_ACTIONS, preset, Some(&file.global))?,
+            views,
+        })
+    }
+
+    //Mode a view opens in. Lists search as you type unless the preset has a search key for that.
+    pub fn base_mode(&self, view: &str) -> InputMode {
+        if is_text_entry(view) {
+            InputMode::Insert
+        } else if is_searchable(view) && self.preset != "vim" {
+            InputMode::Search
+        } else {
+            InputMode::Normal
+        }
+    }
+
+    //Keys bound to a global action, as written in the keymap file
+    pub fn keys_for(&self, action: &str) -> Vec<String> {
+        self.global
+            .iter()
+            .filter(|binding| binding.action == action)
+            .map(|binding| binding.label.clone())
+            .collect()
+    }
+
+    //Action bound to a key in a view. View bindings win over global ones.
+    pub fn action(&self, view: &str, key: &KeyEvent) -> Option<Action> {
+        let name = self
+            .views
+            .get(view)
+            .into_iter()
+            .flatten()
+            .chain(self.global.iter())
+            .find(|binding| same_key(&binding.key, key))
+            .map(|binding| binding.action.clone())?;
+        view_actions(view)
+            .iter()
+            .chain(GLOBAL_ACTIONS.iter())
+            .find(|action| action.name == name)
+            .copied()
+    }
+
+    //Key event the view expects for a bound key, or the key unchanged
+    pub fn translate(&self, view: &str, key: KeyEvent) -> KeyEvent {
+        match self.action(view, &key) {
+            Some(action) if !action.key.is_empty() => parse_key(action.key).unwrap_or(key),
+            _ => key,
+        }
+    }
+
+    //Built-in key of an action the keymap file moved to other keys, which the view shouldn't see
+    pub fn is_replaced(&self, view: &str, key: &KeyEvent) -> bool {
+        self.action(view, key).is_none()
+            && view_actions(view)
+                .iter()
+                .chain(GLOBAL_ACTIONS.iter())
+                .any(|action| parse_key(action.key).is_ok_and(|k| same_key(&k, key)))
+    }
+
+    //(keys, description) of an action in a view, or None when nothing is bound to it
+    pub fn describe(&self, view: &str, name: &str) -> Option<(String, String)> {
+        let action = view_actions(view).iter().chain(GLOBAL_ACTIONS.iter()).find(|a| a.name == name)?;
+        let keys = self
+            .views
+            .get(view)
+            .into_iter()
+            .flatten()
+            .chain(self.global.iter())
+            .filter(|binding| binding.action == name)
+            .map(|binding| binding.label.clone())
+            .collect::<Vec<String>>();
+        if keys.is_empty() {
+            None
+        } else {
+            Some((keys.join(", "), action.description.to_string()))
+        }
+    }
+
+    //Every action available in a view, for the help overlay
+    pub fn describe_view(&self, view: &str) -> Vec<(String, String)> {
+        view_actions(view)
+            .iter()
+            .chain(GLOBAL_ACTIONS.iter())
+            .filter_map(|action| self.describe(view, action.name))
+            .collect()
+    }
+}
+
+pub fn get_keymap_path() -> String {
+    format!("{}/{}", cache::get_cache_dir(), "keymap.json")
+}
+
+//The keymap file, or the default keymap when there is none
+pub fn read() -> Result<Keymap, String> {
+    let path = get_keymap_path();
+    if !std::path::Path::new(&path).exists() {
+        return Ok(Keymap::default());
+    }
+    let data = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path, e))?;
+    let file: KeymapFile = serde_json::from_str(&data).map_err(|e| format!("{}: {}", path, e))?;
+    Keymap::from_file(&file).map_err(|e| format!("{}: {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(name: &str) -> KeyEvent {
+        parse_key(name).unwrap()
+    }
+
+    #[test]
+    fn parses_key_names() {
+        assert_eq!(key("j").code, KeyCode::Char('j'));
+        assert_eq!(key("F10").code, KeyCode::F(10));
+        assert_eq!(key("Ctrl-p"), KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        assert_eq!(key("Space").code, KeyCode::Char(' '));
+        assert_eq!(key("Alt-Left"), KeyEvent::new(KeyCode::Left, KeyModifiers::ALT));
+        assert!(parse_key("F13").is_err());
+        assert!(parse_key("Hyper").is_err());
+    }
+
+    #[test]
+    fn vim_preset_translates_to_view_keys() {
+        let file = KeymapFile { preset: String::from("vim"), ..KeymapFile::default() };
+        let keymap = Keymap::from_file(&file).unwrap();
+        assert_eq!(keymap.translate("Items", key("j")).code, KeyCode::Down);
+        assert_eq!(keymap.translate("Items", key("h")).code, KeyCode::Left);
+        assert_eq!(keymap.action("Items", &key("q")).map(|a| a.name), Some(QUIT));
+        assert_eq!(keymap.action("Items", &key("Esc")), None);
+        assert_eq!(keymap.translate("Items", key("x")).code, KeyCode::Char('x'));
+    }
+
+    #[test]
+    fn views_open_in_their_input_mode() {
+        let keymap = Keymap::default();
+        assert_eq!(keymap.base_mode("Items"), InputMode::Search);
+        assert_eq!(keymap.base_mode("NewItem"), InputMode::Insert);
+        assert_eq!(keymap.base_mode("ItemOptions"), InputMode::Normal);
+
+        let file = KeymapFile { preset: String::from("vim"), ..KeymapFile::default() };
+        let vim = Keymap::from_file(&file).unwrap();
+        assert_eq!(vim.base_mode("Items"), InputMode::Normal);
+        assert_eq!(vim.keys_for(SEARCH), vec!["/"]);
+    }
+
+    #[test]
+    fn file_bindings_replace_preset_keys() {
+        let file: KeymapFile = serde_json::from_str(
+            r#"{"views": {"Items": {"create_item": ["n"]}}, "global": {"quit": ["Ctrl-c"]}}"#,
+        )
+        .unwrap();
+        let keymap = Keymap::from_file(&file).unwrap();
+        assert_eq!(keymap.translate("Items", key("n")).code, KeyCode::F(3));
+        assert_eq!(keymap.translate("Groups", key("n")).code, KeyCode::Char('n'));
+        assert_eq!(keymap.action("Items", &key("Esc")), None);
+        assert_eq!(keymap.describe("Items", "create_item"), Some((String::from("n"), String::from("Create Item"))));
+        assert!(keymap.describe_view("Items").contains(&(String::from("n"), String::from("Create Item"))));
+
+        //The built-in key no longer reaches the view
+        assert!(keymap.is_replaced("Items", &key("F3")));
+        assert!(!keymap.is_replaced("Items", &key("F4")));
+        assert!(!keymap.is_replaced("Groups", &key("F3")));
+
+        let unknown: KeymapFile = serde_json::from_str(r#"{"global": {"fly": ["f"]}}"#).unwrap();
+        assert!(Keymap::from_file(&unknown).is_err());
+    }
+}
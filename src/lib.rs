@@ -1,8 +1,22 @@
+pub mod api;
 pub mod app;
+pub mod bulk;
 pub mod cache;
+pub mod cli;
 pub mod components;
+pub mod dashboard;
+pub mod events;
+pub mod filter;
+pub mod fixtures;
+pub mod fuzzy;
+pub mod history;
+pub mod index;
+pub mod keymap;
 pub mod monday;
+pub mod mouse;
 pub mod objects;
 pub mod queries;
+pub mod router;
+pub mod sort;
 pub mod utils;
 pub mod views;
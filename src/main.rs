@@ -1,100 +1,78 @@
-use crossterm::event::KeyCode;
-use clap::{App};
 
+pub mod api;
 pub mod app;
+pub mod bulk;
 pub mod cache;
+pub mod cli;
 pub mod components;
+pub mod dashboard;
 pub mod events;
+pub mod filter;
+pub mod fixtures;
+pub mod fuzzy;
+pub mod history;
+pub mod index;
+pub mod keymap;
 pub mod monday;
+pub mod mouse;
 pub mod objects;
 pub mod queries;
+pub mod router;
+pub mod sort;
 pub mod utils;
 pub mod views;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let _matches = App::new("Monday TUI")
-                          .version("0.1.1")
-                          .author("Nate D.")
-                          .about("Monday Terminal User Interface for very basic interaction with Monday.com project management software.")
-                          .get_matches();
+    let matches = cli::build().get_matches();
+    if cli::run(&matches)? {
+        return Ok(());
+    }
+    //Read before the terminal is taken over so a bad keymap file is reported plainly
+    let keymap = keymap::read()?;
+    let api = cli::get_api(&matches)?;
+    //The demo account keeps its own cache and index
+    if matches.is_present("demo") {
+        std::env::set_var("MONDAY_DIR", format!("{}/demo", cache::get_cache_dir()));
+    }
 
-    //Terminal
+    //Terminal, restored when this returns, errors or panics
     let mut terminal = app::start_terminal();
     //Receiver Channel
     let rx = events::start_input_handling();
     //Menu
-    let mut app = app::App::new();
+    let mut app = app::App::new(api);
+    app.keymap = keymap;
+    //Views
+    let router = router::Router::new();
 
     loop {
+        app.sync_view();
+
         //Draw frame
-        terminal.draw(|mut rect| match app.active_menu_item {
-            views::MenuItem::Home => views::Home::render(&mut rect, &app),
-            views::MenuItem::Boards => views::BoardList::render(&mut rect, &app),
-            views::MenuItem::Groups => views::GroupList::render(&mut rect, &mut app), 
-            views::MenuItem::GroupsForMove => views::GroupListForMove::render(&mut rect, &mut app),
-            views::MenuItem::Items => views::ItemList::render(&mut rect, &mut app),
-            views::MenuItem::ItemDetail => views::ItemDetail::render(&mut rect, &app),
-            views::MenuItem::ItemOptions => views::ItemOptions::render(&mut rect, &mut app),
-            views::MenuItem::ItemUpdate => views::ItemUpdate::render(&mut rect, &mut app),
-            views::MenuItem::NewItem => views::NewItem::render(&mut rect, &mut app), 
-            views::MenuItem::UserColumnOptions => views::UserColumnOptions::render(&mut rect, &mut app),
-            views::MenuItem::UserOptions => views::UserOptions::render(&mut rect, &mut app),
-            views::MenuItem::StatusColumnOptions => views::StatusColumnOptions::render(&mut rect, &mut app),
-            views::MenuItem::StatusOptions => views::StatusOptions::render(&mut rect, &mut app)
+        terminal.draw(|mut rect| {
+            router.render(&mut rect, &mut app);
         })?;
 
         //Deal with input
         match rx.recv()? {
             events::Event::Input(event) => {
-                //Quit
-                if event.code == KeyCode::Esc {
+                if events::handle_input(event, &mut app, &router) {
                     //Save app state
                     app.cache.app_state = app::AppState::from(app.clone()); 
-                    cache::write(&app.cache).expect("could not save cache");
+                    app.write_cache();
                     //Stop terminal
-                    app::stop_terminal(&mut terminal);
+                    drop(terminal);
                     break;
                 }
-
-                // Key Input
-                events::handle_key_input(event, &mut app);
-
-                //View events
-                match app.active_menu_item {
-                    views::MenuItem::Home => views::Home.process_input_event(event, &mut app),
-                    views::MenuItem::Boards => {
-                        views::BoardList.process_input_event(event, &mut app)
-                    }
-                    views::MenuItem::Items => views::ItemList.process_input_event(event, &mut app),
-                    views::MenuItem::Groups => views::GroupList.process_input_event(event, &mut app), 
-                    views::MenuItem::GroupsForMove => views::GroupListForMove.process_input_event(event, &mut app), 
-                    views::MenuItem::ItemDetail => {
-                        views::ItemDetail.process_input_event(event, &mut app)
-                    }
-                    views::MenuItem::ItemOptions => {
-                        views::ItemOptions.process_input_event(event, &mut app)
-                    }
-                    views::MenuItem::ItemUpdate => {
-                        views::ItemUpdate.process_input_event(event, &mut app)
-                    }, 
-                    views::MenuItem::NewItem => {
-                        views::NewItem.process_input_event(event, &mut app)
-                    }, 
-                    views::MenuItem::StatusColumnOptions => {
-                        views::StatusColumnOptions.process_input_event(event, &mut app)
-                    }, 
-                    views::MenuItem::StatusOptions => {
-                        views::StatusOptions.process_input_event(event, &mut app)
-                    },
-                    views::MenuItem::UserColumnOptions => {
-                        views::UserColumnOptions.process_input_event(event, &mut app)
-                    }, 
-                    views::MenuItem::UserOptions => {
-                        views::UserOptions.process_input_event(event, &mut app)
-                    } 
-                }
             }
-            events::Event::Tick => {}
+            events::Event::Mouse(event) => {
+                events::handle_mouse(event, &mut app, &router);
+            }
+            //The next frame is drawn at the new size
+            events::Event::Resize => {}
+            events::Event::Tick => {
+                router.tick(&mut app);
+            }
         }
     }
     Ok(())
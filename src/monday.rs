@@ -1,13 +1,30 @@
-use graphql_client::reqwest::post_graphql_blocking;
+use super::fixtures;
 use graphql_client::{GraphQLQuery, Response};
-use reqwest::blocking::Client;
+use std::path::PathBuf;
 
 const MONDAY_URL: &str = "https://api.monday.com/v2";
 
-pub fn get_client() -> Result<Client, reqwest::Error> {
+//HTTP client for the API, and whether its traffic is saved as fixtures or answered from them
+#[derive(Debug, Clone)]
+pub struct Client {
+    pub http: reqwest::blocking::Client,
+    pub fixtures: Option<fixtures::Mode>,
+}
+
+impl Client {
+    //Client that only answers from the fixtures under dir, so it needs no token
+    pub fn replay(dir: PathBuf) -> Client {
+        Client {
+            http: reqwest::blocking::Client::new(),
+            fixtures: Some(fixtures::Mode::Replay(dir)),
+        }
+    }
+}
+
+pub fn get_client() -> Result<reqwest::blocking::Client, reqwest::Error> {
     let monday_api_token =
         std::env::var("MONDAY_API_TOKEN").expect("Missing MONDAY_API_TOKEN env var");
-    Client::builder()
+    reqwest::blocking::Client::builder()
         .user_agent("monday-rust/0.1.0")
         .default_headers(
             std::iter::once((
@@ -18,11 +35,35 @@ pub fn get_client() -> Result<Client, reqwest::Error> {
         )
         .build()
 }
+
+fn post(client: &Client, body: &serde_json::Value) -> Result<serde_json::Value, reqwest::Error> {
+    match &client.fixtures {
+        Some(fixtures::Mode::Replay(dir)) => Ok(fixtures::answer(dir, body)),
+        Some(fixtures::Mode::Record(dir)) => {
+            let response = client.http.post(MONDAY_URL).json(body).send()?.json::<serde_json::Value>()?;
+            let exchange = fixtures::Exchange {
+                request: body.clone(),
+                response: response.clone(),
+            };
+            fixtures::save(dir, exchange).expect("could not save fixture");
+            Ok(response)
+        }
+        None => client.http.post(MONDAY_URL).json(body).send()?.json::<serde_json::Value>(),
+    }
+}
+
 pub fn query<Q: GraphQLQuery>(
     client: &Client,
     variables: Q::Variables,
 ) -> Result<Response<Q::ResponseData>, reqwest::Error> {
-    let res: Response<Q::ResponseData> =
-        post_graphql_blocking::<Q, _>(&client, MONDAY_URL, variables).expect("Request failed.");
+    let body = serde_json::to_value(Q::build_query(variables)).expect("Could not serialize query.");
+    let response = post(client, &body).expect("Request failed.");
+    let res: Response<Q::ResponseData> = serde_json::from_value(response).expect("Invalid response.");
     Ok(res)
 }
+
+//For documents built at runtime (e.g. batched mutations) that have no GraphQLQuery type
+pub fn raw_query(client: &Client, query: String) -> Result<serde_json::Value, reqwest::Error> {
+    let body = serde_json::json!({ "query": query });
+    post(client, &body)
+}
@@ -0,0 +1,193 @@
+use super::views::MenuItem;
+use std::collections::HashMap;
+use tui::layout::Rect;
+
+//Views drop what the mouse can click into Targets as they draw, so a click is matched against
+//the frame the user is looking at. Lists also keep their scroll offsets here: tui keeps its own
+//copy private, so these follow the same rules to tell which row was clicked.
+
+//Views opened by the menu tabs, in tab order
+pub const TAB_VIEWS: [MenuItem; 5] = [
+    MenuItem::Home,
+    MenuItem::Boards,
+    MenuItem::Groups,
+    MenuItem::Items,
+    MenuItem::ItemDetail,
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListTarget {
+    //Where the rows are drawn, inside the list's border
+    pub area: Rect,
+    //First row shown
+    pub offset: usize,
+    pub len: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Targets {
+    pub tabs: Vec<(Rect, MenuItem)>,
+    pub list: Option<ListTarget>,
+    pub links: Vec<(Rect, String)>,
+    //Scroll offset of the shown list, and of every list by list key
+    pub list_offset: usize,
+    pub list_offsets: HashMap<String, usize>,
+}
+
+impl Targets {
+    //Forgets the last frame's targets; scroll offsets are kept
+    pub fn clear(&mut self) {
+        self.tabs = Vec::new();
+        self.list = None;
+        self.links = Vec::new();
+    }
+
+    pub fn tab_at(&self, column: u16, row: u16) -> Option<MenuItem> {
+        self.tabs
+            .iter()
+            .find(|(rect, _)| contains(*rect, column, row))
+            .map(|(_, menu_item)| *menu_item)
+    }
+
+    //Index of the list row under the mouse
+    pub fn row_at(&self, column: u16, row: u16) -> Option<usize> {
+        let list = self.list.as_ref()?;
+        if !contains(list.area, column, row) {
+            return None;
+        }
+        let index = list.offset + (row - list.area.y) as usize;
+        if index < list.len {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    pub fn link_at(&self, column: u16, row: u16) -> Option<String> {
+        self.links
+            .iter()
+            .find(|(rect, _)| contains(*rect, column, row))
+            .map(|(_, url)| url.clone())
+    }
+}
+
+pub fn contains(rect: Rect, column: u16, row: u16) -> bool {
+    column >= rect.x && column < rect.right() && row >= rect.y && row < rect.bottom()
+}
+
+//First row a list of one-line rows shows, given the one it showed last time. Same as tui's
+//List and Table: the previous offset is kept unless the selection has moved out of view.
+pub fn list_offset(previous: usize, selected: usize, len: usize, rows: usize) -> usize {
+    if len == 0 || rows == 0 {
+        return previous;
+    }
+    let selected = selected.min(len - 1);
+    let end = len.min(previous + rows).max(previous);
+    if selected >= end {
+        return previous.max((selected + 1).saturating_sub(rows));
+    }
+    previous.min(selected)
+}
+
+//Where each tab title lands in a Tabs widget with a border and a one character divider
+pub fn tab_rects(area: Rect, titles: &[String]) -> Vec<Rect> {
+    let inner = Rect {
+        x: area.x.saturating_add(1),
+        y: area.y.saturating_add(1),
+        width: area.width.saturating_sub(2),
+        height: area.height.saturating_sub(2),
+    };
+    let mut rects = Vec::new();
+    if inner.height < 1 {
+        return rects;
+    }
+    let mut x = inner.left();
+    for title in titles {
+        x = x.saturating_add(1);
+        if x >= inner.right() {
+            break;
+        }
+        let width = (title.chars().count() as u16).min(inner.right() - x);
+        rects.push(Rect { x, y: inner.top(), width, height: 1 });
+        //Title, a space and the divider
+        x = x.saturating_add(width).saturating_add(2);
+    }
+    rects
+}
+
+//Byte ranges of the http(s) links in a piece of text, without trailing punctuation
+pub fn find_links(text: &str) -> Vec<(usize, usize)> {
+    let mut links = Vec::new();
+    let mut from = 0;
+    while let Some(found) = ["https://", "http://"]
+        .iter()
+        .filter_map(|scheme| text[from..].find(scheme))
+        .min()
+    {
+        let start = from + found;
+        let rest = &text[start..];
+        let length = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let url = rest[..length].trim_end_matches(|c| ".,;:!?)]}>'\"".contains(c));
+        let end = start + url.len();
+        if url.len() > "https://".len() {
+            links.push((start, end));
+        }
+        from = start + length.max(1);
+    }
+    links
+}
+
+//Hands a link to the desktop's default handler
+pub fn open_link(url: &str) -> Result<(), String> {
+    let mut command = if cfg!(target_os = "macos") {
+        std::process::Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        std::process::Command::new("xdg-open")
+    };
+    command
+        .arg(url)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Could not open {}: {}", url, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_follows_the_selection() {
+        //Selection in view keeps the offset
+        assert_eq!(list_offset(0, 3, 20, 5), 0);
+        assert_eq!(list_offset(4, 6, 20, 5), 4);
+        //Past the bottom scrolls just far enough, above the top scrolls up to it
+        assert_eq!(list_offset(0, 7, 20, 5), 3);
+        assert_eq!(list_offset(6, 2, 20, 5), 2);
+        assert_eq!(list_offset(0, 0, 0, 5), 0);
+    }
+
+    #[test]
+    fn tabs_are_spaced_by_their_divider() {
+        let titles = vec![String::from("Home"), String::from("Boards")];
+        let rects = tab_rects(Rect::new(2, 2, 40, 3), &titles);
+        assert_eq!(rects[0], Rect::new(4, 3, 4, 1));
+        assert_eq!(rects[1], Rect::new(11, 3, 6, 1));
+    }
+
+    #[test]
+    fn links_stop_at_spaces_and_punctuation() {
+        let text = "See https://example.com/a?b=1, or (http://x.io).";
+        let links = find_links(text)
+            .into_iter()
+            .map(|(start, end)| &text[start..end])
+            .collect::<Vec<&str>>();
+        assert_eq!(links, vec!["https://example.com/a?b=1", "http://x.io"]);
+    }
+}
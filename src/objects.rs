@@ -11,33 +11,119 @@ use serde::{Serialize, Deserialize};
 //     user_id: String,
 // }
 
-// #[derive(Debug, Serialize, Deserialize, Copy, Clone)]
-// enum BoardKind {
-//     Public,
-//     Private,
-//     Share,
-// }
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+pub enum BoardKind {
+    Public,
+    Private,
+    Share,
+}
 
-// #[derive(Debug, Serialize, Deserialize, Clone)]
-// pub struct Column {
+impl BoardKind {
+    pub fn next(self) -> BoardKind {
+        match self {
+            BoardKind::Public => BoardKind::Private,
+            BoardKind::Private => BoardKind::Share,
+            BoardKind::Share => BoardKind::Public,
+        }
+    }
+}
+
+impl std::fmt::Display for BoardKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BoardKind::Public => write!(f, "public"),
+            BoardKind::Private => write!(f, "private"),
+            BoardKind::Share => write!(f, "share"),
+        }
+    }
+}
+
+impl std::str::FromStr for BoardKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<BoardKind, String> {
+        match s {
+            "public" => Ok(BoardKind::Public),
+            "private" => Ok(BoardKind::Private),
+            "share" => Ok(BoardKind::Share),
+            _ => Err(format!("unknown board kind: {}", s)),
+        }
+    }
+}
+
+//What gets copied when a board is duplicated
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq)]
+pub enum DuplicateBoardType {
+    Structure,
+    Pulses,
+    PulsesAndUpdates,
+}
+
+impl std::fmt::Display for DuplicateBoardType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DuplicateBoardType::Structure => write!(f, "structure"),
+            DuplicateBoardType::Pulses => write!(f, "pulses"),
+            DuplicateBoardType::PulsesAndUpdates => write!(f, "pulses-and-updates"),
+        }
+    }
+}
+
+impl std::str::FromStr for DuplicateBoardType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<DuplicateBoardType, String> {
+        match s {
+            "structure" => Ok(DuplicateBoardType::Structure),
+            "pulses" => Ok(DuplicateBoardType::Pulses),
+            "pulses-and-updates" => Ok(DuplicateBoardType::PulsesAndUpdates),
+            _ => Err(format!("unknown duplicate type: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Column {
     // archived: bool,
-    // id: String,
+    pub id: String,
     // pos: String,
     // settings_str: String,
-    // title: String,
+    pub title: String,
+    pub type_: String,
     // width: u32,
-// }
+}
+
+impl Column {
+    //Older boards report status columns as "color"
+    pub fn is_status(&self) -> bool {
+        self.type_ == "color" || self.type_ == "status"
+    }
+
+    pub fn is_people(&self) -> bool {
+        self.type_ == "multiple-person" || self.type_ == "people"
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Label {
     pub column_id : String,
+    #[serde(default)]
+    pub column_title : String,
     pub name : String, 
 }
 
+impl Label {
+    //The label's text. Names come from the API as JSON strings, quotes included.
+    pub fn text(&self) -> String {
+        serde_json::from_str::<String>(&self.name).unwrap_or_else(|_| self.name.clone())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Group {
     // pub archived: bool,
-    // pub color: String,
+    #[serde(default)]
+    pub color: String,
     // pub deleted: bool,
     pub id: String,
     // pub items: Vec<Item>,
@@ -48,6 +134,7 @@ pub struct Group {
 impl Group {
     pub fn new() -> Group {
         Group {
+            color : "".to_string(), 
             id : "".to_string(), 
             title: "".to_string(),
         }
@@ -264,7 +351,7 @@ pub struct Reply {
 //     Closed,
 // }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Board {
     // activity_logs : Vec<ActivityLogType>
     // board_folder_id : u32,
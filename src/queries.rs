@@ -1,8 +1,7 @@
 use super::monday;
 use super::objects::*;
-use super::app; 
 use graphql_client::{GraphQLQuery, Response};
-use reqwest::blocking::Client;
+use super::monday::Client;
 use serde_json::{
     map::Map,
     value::Value
@@ -51,6 +50,108 @@ fn parse_board_list_response(res: Response<board_list::ResponseData>) -> Vec<Boa
     return boards;
 }
 
+//CREATE BOARD
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/create_board.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct CreateBoard;
+
+pub fn create_board(client: &Client, board_name: String, kind: BoardKind, workspace_id: Option<i64>) -> String {
+    let board_kind = match kind {
+        BoardKind::Public => create_board::BoardKind::public,
+        BoardKind::Private => create_board::BoardKind::private,
+        BoardKind::Share => create_board::BoardKind::share,
+    };
+    let variables = create_board::Variables {
+        board_name,
+        board_kind,
+        workspace_id,
+    };
+    let res = monday::query::<CreateBoard>(client, variables).expect("Could not execute query.");
+    let data = res.data.expect("no data in response");
+    data.create_board.unwrap().id
+}
+
+//DUPLICATE BOARD
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/duplicate_board.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct DuplicateBoard;
+
+pub fn duplicate_board(
+    client: &Client,
+    board_id: String,
+    duplicate: DuplicateBoardType,
+    board_name: Option<String>,
+    workspace_id: Option<i64>,
+    keep_subscribers: bool,
+) -> String {
+    let duplicate_type = match duplicate {
+        DuplicateBoardType::Structure => duplicate_board::DuplicateBoardType::duplicate_board_with_structure,
+        DuplicateBoardType::Pulses => duplicate_board::DuplicateBoardType::duplicate_board_with_pulses,
+        DuplicateBoardType::PulsesAndUpdates => {
+            duplicate_board::DuplicateBoardType::duplicate_board_with_pulses_and_updates
+        }
+    };
+    let variables = duplicate_board::Variables {
+        board_id: board_id.parse::<i64>().unwrap(),
+        duplicate_type,
+        board_name,
+        workspace_id,
+        keep_subscribers: Some(keep_subscribers),
+    };
+    let res = monday::query::<DuplicateBoard>(client, variables).expect("Could not execute query.");
+    let data = res.data.expect("no data in response");
+    data.duplicate_board.unwrap().board.id
+}
+
+//ARCHIVE BOARD
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/archive_board.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct ArchiveBoard;
+
+pub fn archive_board(client: &Client, board_id: String) {
+    let variables = archive_board::Variables {
+        board_id: board_id.parse::<i64>().unwrap(),
+    };
+    let res = monday::query::<ArchiveBoard>(client, variables).expect("Could not execute query.");
+    let _data = res.data.expect("no data in response");
+}
+
+//ADD SUBSCRIBERS
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/add_subscribers.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct AddSubscribers;
+
+pub fn add_subscribers(client: &Client, board_id: String, user_ids: Vec<i64>, owner: bool) {
+    let kind = if owner {
+        add_subscribers::BoardSubscriberKind::owner
+    } else {
+        add_subscribers::BoardSubscriberKind::subscriber
+    };
+    let variables = add_subscribers::Variables {
+        board_id: board_id.parse::<i64>().unwrap(),
+        user_ids: user_ids.into_iter().map(Some).collect(),
+        kind: Some(kind),
+    };
+    let res = monday::query::<AddSubscribers>(client, variables).expect("Could not execute query.");
+    let _data = res.data.expect("no data in response");
+}
+
 //GROUP LIST
 #[derive(GraphQLQuery)]
 #[graphql(
@@ -84,10 +185,12 @@ fn parse_group_list_response(res: Response<group_list::ResponseData>) -> Vec<Gro
                 Some(b) => Group {
                     title: b.title.to_owned(),
                     id: b.id.to_owned(),
+                    color: b.color.to_owned(),
                 },
                 None => Group {
                     title: "No Title".to_string(),
                     id: "No ID".to_string(),
+                    color: "".to_string(),
                 },
             })
             .collect(),
@@ -96,6 +199,116 @@ fn parse_group_list_response(res: Response<group_list::ResponseData>) -> Vec<Gro
     return groups;
 }
 
+//CREATE GROUP
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/create_group.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct CreateGroup;
+
+pub fn create_group(client: &Client, board_id: String, group_name: String) -> String {
+    let variables = create_group::Variables {
+        board_id: board_id.parse::<i64>().unwrap(),
+        group_name,
+    };
+    let res = monday::query::<CreateGroup>(client, variables).expect("Could not execute query.");
+    let data = res.data.expect("no data in response");
+    data.create_group.unwrap().id
+}
+
+//DUPLICATE GROUP
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/duplicate_group.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct DuplicateGroup;
+
+pub fn duplicate_group(client: &Client, board_id: String, group_id: String, group_title: String) -> String {
+    let variables = duplicate_group::Variables {
+        board_id: board_id.parse::<i64>().unwrap(),
+        group_id,
+        add_to_top: Some(true),
+        group_title: Some(group_title),
+    };
+    let res = monday::query::<DuplicateGroup>(client, variables).expect("Could not execute query.");
+    let data = res.data.expect("no data in response");
+    data.duplicate_group.unwrap().id
+}
+
+//ARCHIVE GROUP
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/archive_group.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct ArchiveGroup;
+
+pub fn archive_group(client: &Client, board_id: String, group_id: String) {
+    let variables = archive_group::Variables {
+        board_id: board_id.parse::<i64>().unwrap(),
+        group_id,
+    };
+    let res = monday::query::<ArchiveGroup>(client, variables).expect("Could not execute query.");
+    let _data = res.data.expect("no data in response");
+}
+
+//DELETE GROUP
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/delete_group.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct DeleteGroup;
+
+pub fn delete_group(client: &Client, board_id: String, group_id: String) {
+    let variables = delete_group::Variables {
+        board_id: board_id.parse::<i64>().unwrap(),
+        group_id,
+    };
+    let res = monday::query::<DeleteGroup>(client, variables).expect("Could not execute query.");
+    let _data = res.data.expect("no data in response");
+}
+
+//UPDATE GROUP
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/update_group.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct UpdateGroup;
+
+fn update_group(client: &Client, board_id: String, group_id: String, group_attribute: update_group::GroupAttributes, new_value: String) {
+    let variables = update_group::Variables {
+        board_id: board_id.parse::<i64>().unwrap(),
+        group_id,
+        group_attribute,
+        new_value,
+    };
+    let res = monday::query::<UpdateGroup>(client, variables).expect("Could not execute query.");
+    let _data = res.data.expect("no data in response");
+}
+
+pub fn rename_group(client: &Client, board_id: String, group_id: String, title: String) {
+    update_group(client, board_id, group_id, update_group::GroupAttributes::title, title);
+}
+
+//Places the group directly above (or below) the neighbouring group
+pub fn move_group(client: &Client, board_id: String, group_id: String, neighbour_id: String, before: bool) {
+    let attribute = if before {
+        update_group::GroupAttributes::relative_position_before
+    } else {
+        update_group::GroupAttributes::relative_position_after
+    };
+    update_group(client, board_id, group_id, attribute, neighbour_id);
+}
+
 //ITEM LIST
 #[derive(GraphQLQuery)]
 #[graphql(
@@ -137,6 +350,15 @@ fn parse_board_detail_response(res: Response<item_list::ResponseData>) -> Vec<It
             let mut item_new = Item::new(); 
             item_new.id = i.id.clone();
             item_new.name = i.name.clone();
+            item_new.updated_at = i.updated_at.clone().unwrap_or_default();
+            item_new.group = Group {
+                id: g.id.clone(),
+                title: g.title.clone(),
+                color: String::from(""),
+            };
+            if let Some(creator) = i.creator {
+                item_new.creator.id = creator.id;
+            }
             //Subscribers
             item_new.subscribers = i
             .subscribers
@@ -150,12 +372,121 @@ fn parse_board_detail_response(res: Response<item_list::ResponseData>) -> Vec<It
                 }
             })
             .collect::<Vec<User>>();
+            //Column Values
+            item_new.column_values = i
+            .column_values
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+            .map(|c| ColumnValue {
+                id: c.id,
+                text: c.text.unwrap_or_default(),
+                title: c.title,
+                type_: c.type_,
+                additional_info: String::from(""),
+            })
+            .collect::<Vec<ColumnValue>>();
             items.push(item_new); 
         }
     }
     return items; 
 }
 
+//BOARD ITEMS
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/board_items.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct BoardItems;
+
+//Every item on a board, across all groups
+//Items asked for per request when a list is fetched a page at a time
+pub const PAGE_SIZE: i64 = 500;
+
+//Fetches pages from 1 on until one comes back short, or stops at the first failed page
+pub fn all_pages<T, E>(mut fetch: impl FnMut(i64) -> Result<Vec<T>, E>) -> Result<Vec<T>, E> {
+    let mut all = Vec::new();
+    let mut page = 1;
+    loop {
+        let results = fetch(page)?;
+        let last = (results.len() as i64) < PAGE_SIZE;
+        all.extend(results);
+        if last {
+            return Ok(all);
+        }
+        page += 1;
+    }
+}
+
+//Every item on the board; a board that isn't found has none
+pub fn board_items(client: &Client, board_id: String) -> Vec<Item> {
+    let board_id = board_id.parse::<i64>().expect("can convert to i64");
+    all_pages(|page| board_items_page(client, board_id, page)).expect("Could not execute query.")
+}
+
+fn board_items_page(client: &Client, board_id: i64, page: i64) -> Result<Vec<Item>, reqwest::Error> {
+    let variables = board_items::Variables {
+        board_id: Some(board_id),
+        limit: Some(PAGE_SIZE),
+        page: Some(page),
+    };
+    let res = monday::query::<BoardItems>(client, variables)?;
+    let data = res.data.expect("missing response data.");
+    let board = match data.boards.unwrap_or_default().into_iter().flatten().next() {
+        Some(board) => board,
+        None => return Ok(Vec::new()),
+    };
+    let items = board
+        .items
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .map(|i| {
+            let mut item = Item::new();
+            item.id = i.id;
+            item.name = i.name;
+            item.updated_at = i.updated_at.unwrap_or_default();
+            if let Some(creator) = i.creator {
+                item.creator.id = creator.id;
+            }
+            if let Some(group) = i.group {
+                item.group = Group {
+                    id: group.id,
+                    title: group.title,
+                    color: String::from(""),
+                };
+            }
+            item.subscribers = i
+                .subscribers
+                .into_iter()
+                .flatten()
+                .map(|sub| User {
+                    id: sub.id,
+                    email: String::from(""),
+                    name: String::from(""),
+                })
+                .collect::<Vec<User>>();
+            item.column_values = i
+                .column_values
+                .unwrap_or_default()
+                .into_iter()
+                .flatten()
+                .map(|c| ColumnValue {
+                    id: c.id,
+                    text: c.text.unwrap_or_default(),
+                    title: c.title,
+                    type_: c.type_,
+                    additional_info: String::from(""),
+                })
+                .collect::<Vec<ColumnValue>>();
+            item
+        })
+        .collect::<Vec<Item>>();
+    Ok(items)
+}
+
 //ITEM DETAIL
 #[derive(GraphQLQuery)]
 #[graphql(
@@ -166,6 +497,8 @@ fn parse_board_detail_response(res: Response<item_list::ResponseData>) -> Vec<It
 struct ItemDetail;
 
 type Date = String;
+//Named after the schema's scalar, which the generated code refers to
+#[allow(clippy::upper_case_acronyms)]
 type JSON = String;
 
 pub fn item_detail(client: &Client, item_id: String) -> Item {
@@ -194,7 +527,8 @@ fn parse_item_detail_response(res: Response<item_detail::ResponseData>) -> Item
     //Group
     output.group = Group {
         title: item.group.unwrap().title,
-        id : "".to_string()
+        id : "".to_string(),
+        color : "".to_string()
     };
     //Board
     output.board = Board {
@@ -299,6 +633,7 @@ pub fn create_update(client: &Client, item_id: String, body: String) -> String {
 )]
 struct BoardColumns;
 
+//Labels of every status column on the board
 pub fn board_columns(client: &Client, board_id: String) -> Vec<Label> {
     let variables = board_columns::Variables {
         board_id: Some(board_id.parse::<i64>().unwrap()),
@@ -320,23 +655,47 @@ fn parse_board_columns(res: Response<board_columns::ResponseData>) -> Vec<Label>
         .unwrap();
     for col in board.columns.unwrap().iter() {
         let column = col.clone().unwrap(); 
+        //Dropdowns have labels too, but only status labels can be set as a status
+        if column.type_ != "color" && column.type_ != "status" {
+            continue;
+        }
         let setting : serde_json::Value = serde_json::from_str(&column.settings_str.clone()).unwrap();
-        match setting.get("labels") {
-            Some(v) => {
-                let label_map: Map<String, Value> = v.as_object().unwrap().clone();
-                for label in label_map.values() {
-                    labels.push(Label {
-                        column_id : column.id.clone(),
-                        name : label.to_string()
-                    }); 
-                }
-            }, 
-            None => {}
-        }; 
+        if let Some(v) = setting.get("labels") {
+            let label_map: Map<String, Value> = v.as_object().unwrap().clone();
+            for label in label_map.values() {
+                labels.push(Label {
+                    column_id : column.id.clone(),
+                    column_title : column.title.clone(),
+                    name : label.to_string()
+                }); 
+            }
+        }
     }; 
     labels
 }
 
+//The board's column schema
+pub fn column_schema(client: &Client, board_id: String) -> Vec<Column> {
+    let variables = board_columns::Variables {
+        board_id: Some(board_id.parse::<i64>().unwrap()),
+    };
+    let res: Response<board_columns::ResponseData> =
+        monday::query::<BoardColumns>(client, variables).expect("Could not execute query.");
+    let data = res.data.expect("missing response data");
+    data.boards
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .flat_map(|board| board.columns.unwrap_or_default())
+        .flatten()
+        .map(|column| Column {
+            id: column.id,
+            title: column.title,
+            type_: column.type_,
+        })
+        .collect()
+}
+
 //Change status column
 #[derive(GraphQLQuery)]
 #[graphql(
@@ -346,23 +705,15 @@ fn parse_board_columns(res: Response<board_columns::ResponseData>) -> Vec<Label>
 )]
 struct ChangeStatus;
 
-pub fn change_status(app : &app::App, value : String) {
-    let status_column = app
-        .cache
-        .boards
-        .iter()
-        .filter(|board| board.id == app.item_detail.board.id)
-        .nth(0)
-        .expect("no status column for board");
-        
+pub fn change_status(client: &Client, board_id : String, item_id : String, column_id : String, value : String) {
     let variables = change_status::Variables {
-        item_id : Some(app.item_detail.id.parse::<i64>().unwrap()), 
-        column_id : status_column.status_column_id.clone(), 
-        board_id : app.item_detail.board.id.parse::<i64>().unwrap(), 
+        item_id : Some(item_id.parse::<i64>().unwrap()), 
+        column_id, 
+        board_id : board_id.parse::<i64>().unwrap(), 
         value : format!("{{\"label\":\"{}\"}}", value.replace("\"", "")) 
     };
     
-    monday::query::<ChangeStatus>(&app.client, variables).expect("Could not execute query.");
+    monday::query::<ChangeStatus>(client, variables).expect("Could not execute query.");
 }
 
 //Get current user
@@ -397,15 +748,15 @@ pub fn current_user(client : &Client) -> User {
 )]
 struct CreateItem;
 
-pub fn create_item(item_name : String, app : &app::App) -> Item {
+pub fn create_item(client: &Client, board_id : String, group_id : String, item_name : String) -> Item {
         
     let variables = create_item::Variables {
         item_name : Some(item_name), 
-        board_id : app.board_detail.id.parse::<i64>().unwrap(), 
-        group_id : Some(app.group_detail.id.clone())
+        board_id : board_id.parse::<i64>().unwrap(), 
+        group_id : Some(group_id)
     };
     
-    let res = monday::query::<CreateItem>(&app.client, variables).expect("Could not execute query.");
+    let res = monday::query::<CreateItem>(client, variables).expect("Could not execute query.");
     let data = res.data.expect("no data in response");
     let mut item = Item::new();
     item.id = data.create_item.unwrap().id.clone(); 
@@ -421,14 +772,14 @@ pub fn create_item(item_name : String, app : &app::App) -> Item {
 )]
 struct MoveItem;
 
-pub fn move_item(app : &app::App, group_id : String) {
+pub fn move_item(client: &Client, item_id : String, group_id : String) {
         
     let variables = move_item::Variables {
-        item_id : Some(app.item_detail.id.parse::<i64>().unwrap()), 
+        item_id : Some(item_id.parse::<i64>().unwrap()), 
         group_id : group_id
     };
     
-    let res = monday::query::<MoveItem>(&app.client, variables).expect("Could not execute query.");
+    let res = monday::query::<MoveItem>(client, variables).expect("Could not execute query.");
     let _data = res.data.expect("no data in response");
 }
 
@@ -442,13 +793,13 @@ pub fn move_item(app : &app::App, group_id : String) {
 )]
 struct UserList;
 
-pub fn user_list(app : &app::App) -> Vec<User> {
+pub fn user_list(client: &Client, board_id : String) -> Vec<User> {
         
     let variables = user_list::Variables {
-        board_id : Some(app.board_detail.id.parse::<i64>().unwrap()), 
+        board_id : Some(board_id.parse::<i64>().unwrap()), 
     };
     
-    let res = monday::query::<UserList>(&app.client, variables).expect("Could not execute query.");
+    let res = monday::query::<UserList>(client, variables).expect("Could not execute query.");
     let data = res.data.expect("no data in response");
     let board = data
         .boards
@@ -477,21 +828,232 @@ pub fn user_list(app : &app::App) -> Vec<User> {
 )]
 struct AssignUser;
 
-pub fn assign_user(app : &app::App, value : String) {
-    let board_cache = app
-        .cache
-        .boards
-        .iter()
-        .filter(|board| board.id == app.item_detail.board.id)
-        .nth(0)
-        .expect("no cache for board");
-        
+pub fn assign_user(client: &Client, board_id : String, item_id : String, column_id : String, value : String) {
     let variables = assign_user::Variables {
-        item_id : Some(app.item_detail.id.parse::<i64>().unwrap()), 
-        column_id : board_cache.user_column_id.clone(), 
-        board_id : app.board_detail.id.parse::<i64>().unwrap(), 
+        item_id : Some(item_id.parse::<i64>().unwrap()), 
+        column_id, 
+        board_id : board_id.parse::<i64>().unwrap(), 
         value : format!("{{\"personsAndTeams\":[{{\"id\": {}, \"kind\": \"person\"}}]}}", value.replace("\"", "")) 
     };
     
-    monday::query::<AssignUser>(&app.client, variables).expect("Could not execute query.");
-}
\ No newline at end of file
+    monday::query::<AssignUser>(client, variables).expect("Could not execute query.");
+}
+
+//ACCOUNT USERS
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "schema.json",
+    query_path = "queries/account_users.graphql",
+    response_derives = "Debug,Clone"
+)]
+struct AccountUsers;
+
+pub fn account_users(client: &Client) -> Vec<User> {
+    let variables = account_users::Variables {};
+    let res = monday::query::<AccountUsers>(client, variables).expect("Could not execute query.");
+    let data = res.data.expect("no data in response");
+    data.users
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .map(|u| User {
+            id: u.id,
+            name: u.name,
+            email: u.email,
+        })
+        .collect::<Vec<User>>()
+}
+
+
+//BATCHED MUTATIONS
+//Sends every mutation field in a single request, aliased by position, and reports
+//the outcome of each one separately.
+pub fn batch_mutation(client: &Client, fields: &[String]) -> Vec<Result<(), String>> {
+    let body = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| format!("m{}: {}", i, field))
+        .collect::<Vec<String>>()
+        .join("\n");
+    let res = match monday::raw_query(client, format!("mutation {{\n{}\n}}", body)) {
+        Ok(res) => res,
+        Err(e) => return fields.iter().map(|_| Err(e.to_string())).collect(),
+    };
+    let errors = res.get("errors").and_then(|e| e.as_array()).cloned().unwrap_or_default();
+    let error_for = |alias: &str| {
+        errors.iter().find_map(|e| {
+            let path = e.get("path").and_then(|p| p.as_array())?;
+            if path.first().and_then(|p| p.as_str()) == Some(alias) {
+                Some(e.get("message").and_then(|m| m.as_str()).unwrap_or("unknown error").to_string())
+            } else {
+                None
+            }
+        })
+    };
+    (0..fields.len())
+        .map(|i| {
+            let alias = format!("m{}", i);
+            if let Some(message) = error_for(&alias) {
+                return Err(message);
+            }
+            match res.get("data").and_then(|d| d.get(&alias)) {
+                Some(Value::Null) | None => Err(errors
+                    .first()
+                    .and_then(|e| e.get("message"))
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("no data in response")
+                    .to_string()),
+                Some(_) => Ok(()),
+            }
+        })
+        .collect()
+}
+
+//Message of the first GraphQL error in a response without data
+fn first_error(res: &Value) -> String {
+    res.get("errors")
+        .and_then(|e| e.get(0))
+        .and_then(|e| e.get("message"))
+        .and_then(|m| m.as_str())
+        .unwrap_or("no data in response")
+        .to_string()
+}
+
+//Boards to search and, for each, the column ids to match besides the name
+pub struct SearchTarget {
+    pub board_id: String,
+    pub column_ids: Vec<String>,
+}
+
+//Item from a hand-written query's JSON, for the queries built at runtime
+fn parse_item_value(value: &Value) -> Item {
+    let text = |v: &Value, key: &str| v.get(key).and_then(|s| s.as_str()).unwrap_or("").to_string();
+    let mut item = Item::new();
+    item.id = text(value, "id");
+    item.name = text(value, "name");
+    if let Some(board) = value.get("board") {
+        item.board = Board {
+            id: text(board, "id"),
+            name: text(board, "name"),
+        };
+    }
+    if let Some(group) = value.get("group") {
+        item.group = Group {
+            id: text(group, "id"),
+            title: text(group, "title"),
+            color: text(group, "color"),
+        };
+    }
+    item.subscribers = value
+        .get("subscribers")
+        .and_then(|s| s.as_array())
+        .map(|subscribers| {
+            subscribers
+                .iter()
+                .map(|sub| User {
+                    id: sub.get("id").and_then(|i| i.as_i64()).unwrap_or(0),
+                    email: String::from(""),
+                    name: String::from(""),
+                })
+                .collect::<Vec<User>>()
+        })
+        .unwrap_or_default();
+    item.column_values = value
+        .get("column_values")
+        .and_then(|c| c.as_array())
+        .map(|columns| {
+            columns
+                .iter()
+                .map(|c| ColumnValue {
+                    id: text(c, "id"),
+                    text: text(c, "text"),
+                    title: text(c, "title"),
+                    type_: text(c, "type"),
+                    additional_info: String::from(""),
+                })
+                .collect::<Vec<ColumnValue>>()
+        })
+        .unwrap_or_default();
+    item
+}
+
+//Finds items on every target board whose name or searched columns equal the query.
+//All boards go out in one request, each lookup aliased as s0, s1, ...
+pub fn search_items_across_boards(client: &Client, targets: &[SearchTarget], query: &str) -> Result<Vec<Item>, String> {
+    let quote = |s: &str| serde_json::to_string(s).unwrap();
+    let mut fields = Vec::new();
+    for target in targets {
+        let board_id = target.board_id.parse::<i64>().map_err(|_| format!("invalid board id: {}", target.board_id))?;
+        for column_id in std::iter::once("name").chain(target.column_ids.iter().map(|c| c.as_str())) {
+            fields.push(format!(
+                "s{}: items_by_column_values(board_id: {}, column_id: {}, column_value: {}) {{ id name board {{ id name }} group {{ id title color }} column_values {{ id text title type }} }}",
+                fields.len(),
+                board_id,
+                quote(column_id),
+                quote(query)
+            ));
+        }
+    }
+    if fields.is_empty() {
+        return Ok(Vec::new());
+    }
+    let res = monday::raw_query(client, format!("query {{\n{}\n}}", fields.join("\n"))).map_err(|e| e.to_string())?;
+    let data = match res.get("data") {
+        Some(Value::Object(data)) => data.clone(),
+        _ => {
+            return Err(first_error(&res));
+        }
+    };
+    let mut items: Vec<Item> = Vec::new();
+    for i in 0..fields.len() {
+        let found = data.get(&format!("s{}", i)).and_then(|f| f.as_array()).cloned().unwrap_or_default();
+        for value in found.iter() {
+            let item = parse_item_value(value);
+            if !items.iter().any(|existing| existing.id == item.id) {
+                items.push(item);
+            }
+        }
+    }
+    Ok(items)
+}
+
+//Items of several boards, each item tagged with its board, and an error for each board that
+//couldn't be loaded. Boards are fetched one at a time so a failing board only loses its own items.
+pub fn items_across_boards(client: &Client, board_ids: &[String]) -> (Vec<Item>, Vec<String>) {
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+    for board_id in board_ids {
+        match all_pages(|page| board_items_value(client, board_id, page)) {
+            Ok(board_items) => items.extend(board_items),
+            Err(e) => errors.push(format!("board {}: {}", board_id, e)),
+        }
+    }
+    (items, errors)
+}
+
+fn board_items_value(client: &Client, board_id: &str, page: i64) -> Result<Vec<Item>, String> {
+    let query = format!(
+        "query {{ boards(ids: [{}]) {{ id name items(limit: {}, page: {}) {{ id name group {{ id title color }} subscribers {{ id }} column_values {{ id text title type }} }} }} }}",
+        board_id, PAGE_SIZE, page
+    );
+    let res = monday::raw_query(client, query).map_err(|e| e.to_string())?;
+    let boards = match res.get("data").and_then(|d| d.get("boards")).and_then(|b| b.as_array()) {
+        Some(boards) => boards.clone(),
+        None => {
+            return Err(first_error(&res));
+        }
+    };
+    let mut items = Vec::new();
+    for board in boards.iter() {
+        let board_ref = Board {
+            id: board.get("id").and_then(|i| i.as_str()).unwrap_or("").to_string(),
+            name: board.get("name").and_then(|n| n.as_str()).unwrap_or("").to_string(),
+        };
+        for value in board.get("items").and_then(|i| i.as_array()).into_iter().flatten() {
+            let mut item = parse_item_value(value);
+            item.board = board_ref.clone();
+            items.push(item);
+        }
+    }
+    Ok(items)
+}
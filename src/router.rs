@@ -0,0 +1,144 @@
+use super::app;
+use super::components;
+use super::views::{self, MenuItem};
+use crossterm::event::{KeyCode, KeyEvent};
+use std::collections::HashMap;
+use tui::{backend::Backend, terminal::Frame};
+
+//Every screen is a View registered with the Router under its MenuItem. The router draws and
+//feeds input to whichever view is active, so adding a view doesn't touch the main loop.
+pub trait View<B: Backend> {
+    fn title(&self) -> &'static str;
+
+    //Key and description pairs shown in the help bar
+    fn help(&self, _app: &app::App) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App);
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App);
+
+    //Keys the view handles itself before they're typed into the search box
+    fn takes_key(&self, _event: &KeyEvent, _app: &app::App) -> bool {
+        false
+    }
+
+    //Rows in the view's list. Views with a length get the shared Up/Down handling.
+    fn list_len(&self, _app: &app::App) -> Option<usize> {
+        None
+    }
+
+    //Called on every tick, whichever view is shown
+    fn tick(&self, _app: &mut app::App) {}
+}
+
+//Up and Down wrap around the list; an empty list has nothing selected
+pub fn move_selection(app: &mut app::App, len: usize, down: bool) {
+    if len == 0 {
+        app.list_state.select(None);
+        return;
+    }
+    let next = match app.list_state.selected() {
+        Some(selected) => {
+            let selected = selected.min(len - 1);
+            if down { (selected + 1) % len } else { (selected + len - 1) % len }
+        }
+        //Rows just arrived in a list that was empty
+        None => 0,
+    };
+    app.list_state.select(Some(next));
+}
+
+pub struct Router<B: Backend> {
+    views: HashMap<MenuItem, Box<dyn View<B>>>,
+}
+
+impl<B: Backend> Default for Router<B> {
+    fn default() -> Router<B> {
+        Router::new()
+    }
+}
+
+impl<B: Backend> Router<B> {
+    //Router with every built-in view
+    pub fn new() -> Router<B> {
+        let mut router = Router { views: HashMap::new() };
+        router.register(MenuItem::Home, Box::new(views::Home));
+        router.register(MenuItem::QuickSwitch, Box::new(views::QuickSwitch));
+        router.register(MenuItem::Boards, Box::new(views::BoardList));
+        router.register(MenuItem::BoardOptions, Box::new(views::BoardOptions));
+        router.register(MenuItem::NewBoard, Box::new(views::NewBoard));
+        router.register(MenuItem::DuplicateBoard, Box::new(views::DuplicateBoard));
+        router.register(MenuItem::BoardSubscribers, Box::new(views::BoardSubscribers));
+        router.register(MenuItem::BoardSettings, Box::new(views::BoardSettings));
+        router.register(MenuItem::FindItem, Box::new(views::FindItem));
+        router.register(MenuItem::IndexSearch, Box::new(views::IndexSearch));
+        router.register(MenuItem::Groups, Box::new(views::GroupList));
+        router.register(MenuItem::GroupsForMove, Box::new(views::GroupListForMove));
+        router.register(MenuItem::GroupOptions, Box::new(views::GroupOptions));
+        router.register(MenuItem::NewGroup, Box::new(views::NewGroup));
+        router.register(MenuItem::RenameGroup, Box::new(views::RenameGroup));
+        router.register(MenuItem::DuplicateGroup, Box::new(views::DuplicateGroup));
+        router.register(MenuItem::Items, Box::new(views::ItemList));
+        router.register(MenuItem::BulkOptions, Box::new(views::BulkOptions));
+        router.register(MenuItem::BulkProgress, Box::new(views::BulkProgress));
+        router.register(MenuItem::ItemTable, Box::new(views::ItemTable));
+        router.register(MenuItem::TableColumns, Box::new(views::TableColumns));
+        router.register(MenuItem::SortOptions, Box::new(views::SortOptions));
+        router.register(MenuItem::FilterOptions, Box::new(views::FilterOptions));
+        router.register(MenuItem::ViewOptions, Box::new(views::ViewOptions));
+        router.register(MenuItem::SaveView, Box::new(views::SaveView));
+        router.register(MenuItem::ItemDetail, Box::new(views::ItemDetail));
+        router.register(MenuItem::ItemOptions, Box::new(views::ItemOptions));
+        router.register(MenuItem::ItemUpdate, Box::new(views::ItemUpdate));
+        router.register(MenuItem::NewItem, Box::new(views::NewItem));
+        router.register(MenuItem::UserOptions, Box::new(views::UserOptions));
+        router.register(MenuItem::UserColumnOptions, Box::new(views::UserColumnOptions));
+        router.register(MenuItem::StatusColumnOptions, Box::new(views::StatusColumnOptions));
+        router.register(MenuItem::StatusOptions, Box::new(views::StatusOptions));
+        router
+    }
+
+    //Adds a view, or replaces the one registered for the menu item
+    pub fn register(&mut self, menu_item: MenuItem, view: Box<dyn View<B>>) {
+        self.views.insert(menu_item, view);
+    }
+
+    pub fn view(&self, menu_item: MenuItem) -> Option<&dyn View<B>> {
+        self.views.get(&menu_item).map(|view| view.as_ref())
+    }
+
+    //The active view, with the key bindings overlay on top when it's open
+    pub fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        app.mouse.clear();
+        if let Some(view) = self.view(app.active_menu_item) {
+            app.help = view.help(app);
+            view.render(rect, app);
+        }
+        if app.show_keymap {
+            components::render_keymap_overlay(rect, app);
+        }
+    }
+
+    pub fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        if let Some(view) = self.view(app.active_menu_item) {
+            match (event.code, view.list_len(app)) {
+                (KeyCode::Up, Some(len)) => move_selection(app, len, false),
+                (KeyCode::Down, Some(len)) => move_selection(app, len, true),
+                _ => view.handle_event(event, app),
+            }
+        }
+    }
+
+    pub fn takes_key(&self, event: &KeyEvent, app: &app::App) -> bool {
+        self.view(app.active_menu_item)
+            .is_some_and(|view| view.takes_key(event, app))
+    }
+
+    pub fn tick(&self, app: &mut app::App) {
+        for view in self.views.values() {
+            view.tick(app);
+        }
+    }
+}
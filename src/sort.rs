@@ -0,0 +1,183 @@
+use super::cache;
+use super::objects;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SortKey {
+    Name,
+    UpdatedAt,
+    Status,
+    DueDate,
+    Assignee,
+    //Any numeric column, by column id
+    Column(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemSort {
+    pub key: SortKey,
+    pub descending: bool,
+}
+
+//Sort keys offered for a board: the fixed ones plus every numeric column
+pub fn sort_options(items: &[objects::Item]) -> Vec<(SortKey, String)> {
+    let mut options = vec![
+        (SortKey::Name, String::from("Name")),
+        (SortKey::UpdatedAt, String::from("Updated At")),
+        (SortKey::Status, String::from("Status")),
+        (SortKey::DueDate, String::from("Due Date")),
+        (SortKey::Assignee, String::from("Assignee")),
+    ];
+    if let Some(item) = items.first() {
+        for cv in item.column_values.iter().filter(|cv| cv.type_ == "numeric") {
+            options.push((SortKey::Column(cv.id.clone()), cv.title.clone()));
+        }
+    }
+    options
+}
+
+//Text of the column backing a sort key, using the board's chosen column when there is one
+fn column_text<'a>(item: &'a objects::Item, column_id: &str, type_: &str) -> &'a str {
+    item.column_values
+        .iter()
+        .find(|cv| if column_id.is_empty() { cv.type_ == type_ } else { cv.id == column_id })
+        .map(|cv| cv.text.as_str())
+        .unwrap_or("")
+}
+
+//Missing values always go last, whichever way the list is sorted
+fn compare_present<T: PartialOrd>(x: Option<T>, y: Option<T>, descending: bool) -> Ordering {
+    match (x, y) {
+        (Some(x), Some(y)) => {
+            let ordering = x.partial_cmp(&y).unwrap_or(Ordering::Equal);
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn compare(a: &objects::Item, b: &objects::Item, sort: &ItemSort, board_meta: &cache::BoardMeta) -> Ordering {
+    let ordering = match &sort.key {
+        SortKey::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        SortKey::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+        SortKey::Status => {
+            let id = &board_meta.status_column_id;
+            column_text(a, id, "color").cmp(column_text(b, id, "color"))
+        }
+        SortKey::Assignee => {
+            let id = &board_meta.user_column_id;
+            column_text(a, id, "multiple-person").cmp(column_text(b, id, "multiple-person"))
+        }
+        //Dates are ISO formatted, so they compare as text
+        SortKey::DueDate => {
+            let date = |item| Some(column_text(item, "", "date")).filter(|d| !d.is_empty());
+            return compare_present(date(a), date(b), sort.descending);
+        }
+        SortKey::Column(id) => {
+            let number = |item| column_text(item, id, "numeric").parse::<f64>().ok();
+            return compare_present(number(a), number(b), sort.descending);
+        }
+    };
+    if sort.descending {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+pub fn sort_items(items: &mut [objects::Item], sort: &ItemSort, board_meta: &cache::BoardMeta) {
+    items.sort_by(|a, b| compare(a, b, sort, board_meta));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(name: &str, columns: &[(&str, &str, &str)]) -> objects::Item {
+        let mut item = objects::Item::new();
+        item.name = name.to_string();
+        item.column_values = columns
+            .iter()
+            .map(|(id, type_, text)| objects::ColumnValue {
+                additional_info: String::new(),
+                id: id.to_string(),
+                text: text.to_string(),
+                title: id.to_string(),
+                type_: type_.to_string(),
+            })
+            .collect();
+        item
+    }
+
+    fn by(key: SortKey, descending: bool) -> ItemSort {
+        ItemSort { key, descending }
+    }
+
+    #[test]
+    fn names_sort_either_way_ignoring_case() {
+        let (a, b) = (item("apple", &[]), item("Banana", &[]));
+        let meta = cache::BoardMeta::new();
+        assert_eq!(compare(&a, &b, &by(SortKey::Name, false), &meta), Ordering::Less);
+        assert_eq!(compare(&a, &b, &by(SortKey::Name, true), &meta), Ordering::Greater);
+    }
+
+    #[test]
+    fn numeric_columns_compare_as_numbers() {
+        let (nine, ten) = (item("a", &[("points", "numeric", "9")]), item("b", &[("points", "numeric", "10")]));
+        let meta = cache::BoardMeta::new();
+        let key = SortKey::Column(String::from("points"));
+        assert_eq!(compare(&nine, &ten, &by(key.clone(), false), &meta), Ordering::Less);
+        assert_eq!(compare(&nine, &ten, &by(key, true), &meta), Ordering::Greater);
+    }
+
+    #[test]
+    fn text_columns_compare_as_text() {
+        let (done, stuck) = (item("a", &[("status", "color", "Done")]), item("b", &[("status", "color", "Stuck")]));
+        let mut meta = cache::BoardMeta::new();
+        assert_eq!(compare(&done, &stuck, &by(SortKey::Status, false), &meta), Ordering::Less);
+
+        //The board's chosen column wins over the first one of its type
+        let (a, b) = (
+            item("a", &[("status", "color", "Done"), ("phase", "color", "Zeta")]),
+            item("b", &[("status", "color", "Stuck"), ("phase", "color", "Alpha")]),
+        );
+        meta.status_column_id = String::from("phase");
+        assert_eq!(compare(&a, &b, &by(SortKey::Status, false), &meta), Ordering::Greater);
+    }
+
+    #[test]
+    fn due_dates_sort_by_date_with_missing_ones_last() {
+        let early = item("a", &[("due", "date", "2024-01-31")]);
+        let late = item("b", &[("due", "date", "2024-02-01")]);
+        let undated = item("c", &[("due", "date", "")]);
+        let meta = cache::BoardMeta::new();
+        for descending in [false, true] {
+            let sort = by(SortKey::DueDate, descending);
+            assert_eq!(compare(&early, &undated, &sort, &meta), Ordering::Less);
+            assert_eq!(compare(&undated, &late, &sort, &meta), Ordering::Greater);
+            assert_eq!(compare(&undated, &undated, &sort, &meta), Ordering::Equal);
+        }
+        assert_eq!(compare(&early, &late, &by(SortKey::DueDate, false), &meta), Ordering::Less);
+        assert_eq!(compare(&early, &late, &by(SortKey::DueDate, true), &meta), Ordering::Greater);
+
+        let mut items = vec![undated.clone(), late.clone(), early.clone()];
+        sort_items(&mut items, &by(SortKey::DueDate, true), &meta);
+        assert_eq!(items.iter().map(|i| i.name.as_str()).collect::<Vec<&str>>(), vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn missing_numbers_go_last_either_way() {
+        let (some, none) = (item("a", &[("points", "numeric", "3")]), item("b", &[]));
+        let meta = cache::BoardMeta::new();
+        let key = SortKey::Column(String::from("points"));
+        assert_eq!(compare(&some, &none, &by(key.clone(), false), &meta), Ordering::Less);
+        assert_eq!(compare(&some, &none, &by(key, true), &meta), Ordering::Less);
+    }
+}
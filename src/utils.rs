@@ -1,46 +1,39 @@
 use super::objects;
 use super::app; 
-use crossterm::event::KeyCode; 
-use aho_corasick::AhoCorasickBuilder;
-
-fn search(query: String, vector: &Vec<String>) -> Vec<usize> {
-    let patterns = query.split_whitespace();
-    let ac = AhoCorasickBuilder::new()
-        .ascii_case_insensitive(true)
-        .build(patterns);
+use super::cache;
+use super::filter;
+use super::fuzzy;
+use super::sort;
+use tui::style::Color;
+
+fn search(query: String, vector: &[String]) -> Vec<Option<i64>> {
     vector
         .iter()
-        .map(|string| {
-            let matches = ac
-                .find_iter(string)
-                .map(|m| m.pattern())
-                .collect::<Vec<usize>>();
-            matches.len()
-        })
-        .collect::<Vec<usize>>()
+        .map(|string| fuzzy::fuzzy_match(&query, string).map(|m| m.score))
+        .collect::<Vec<Option<i64>>>()
 }
 
 #[derive(Debug, Copy, Clone)]
 struct Match<T> {
     obj: T,
-    count: usize,
+    score: i64,
 }
 
+//Keeps the entries that matched, best match first
 fn filter_by_matches<T: Clone + std::fmt::Debug>(
-    filter_vec: &Vec<T>,
-    match_vec: &Vec<usize>,
+    filter_vec: &[T],
+    match_vec: &[Option<i64>],
 ) -> Vec<T> {
     let zip = filter_vec.iter().zip(match_vec.iter());
     let mut matches: Vec<Match<T>> = zip
-        .map(|(x, m)| Match {
+        .filter_map(|(x, m)| m.map(|score| Match {
             obj: x.clone(),
-            count: *m,
-        })
+            score,
+        }))
         .collect::<Vec<Match<T>>>();
-    matches.sort_by(|a, b| b.count.cmp(&a.count));
+    matches.sort_by_key(|m| std::cmp::Reverse(m.score));
     return matches
         .iter()
-        .filter(|m| m.count > 0)
         .map(|m| m.obj.clone())
         .collect::<Vec<T>>();
 }
@@ -99,27 +92,122 @@ pub fn filter_groups(app : &app::App) -> Vec<objects::Group> {
     return output;
 }
 
+//Plain words keep the ranked name search, field terms are evaluated as a filter.
+//A query that doesn't parse (e.g. a quote still being typed) is searched as plain text.
+pub fn query_items(query: &str, items: &Vec<objects::Item>, ctx: &filter::Context) -> Vec<objects::Item> {
+    match filter::parse(query) {
+        Ok(parsed) => {
+            let (words, rest) = parsed.split_words();
+            let mut output = if words.is_empty() {
+                items.clone()
+            } else {
+                search_items(words.join(" "), items)
+            };
+            output.retain(|item| rest.matches(item, ctx));
+            output
+        }
+        Err(_) => search_items(query.to_string(), items),
+    }
+}
+
+//The toggled filters plus the search box text
+pub fn item_filter(app : &app::App) -> filter::FilterState {
+    let mut state = app.filter.clone();
+    state.query = app.key_input.iter().collect::<String>();
+    state
+}
+
+//The part of an item query that is fuzzy matched against names, for highlighting
+pub fn search_terms(query: &str) -> String {
+    match filter::parse(query) {
+        Ok(parsed) => parsed.split_words().0.join(" "),
+        Err(_) => query.to_string(),
+    }
+}
+
 pub fn filter_items(app : &app::App) -> Vec<objects::Item> {
-    let mut output: Vec<objects::Item>;
+    filter_items_matching(app, &app.key_input)
+}
+
+//The open group's items for a search other than the one being typed
+pub fn filter_items_matching(app : &app::App, query : &[char]) -> Vec<objects::Item> {
+    let mut state = app.filter.clone();
+    state.query = query.iter().collect::<String>();
+    let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+    let ctx = filter::Context::new(&app.current_user, &board_meta);
+
+    //The group filter looks across the whole board, otherwise it's the open group
+    let items = if state.groups.is_empty() { &app.items } else { &app.board_items };
 
     //Filter by search element
-    if app.key_input.len() > 0 {
-        let search_string: String = app.key_input.iter().map(|c| c.to_string()).collect::<String>();
-        output = search_items(search_string, &app.items);
+    let mut output = if state.query.is_empty() {
+        items.clone()
     } else {
-        output = app.items.clone();
+        query_items(&state.query, items, &ctx)
+    };
+
+    output.retain(|item| state.matches(item, &ctx));
+
+    //Fuzzy matches stay best first, the board's sort only orders plain lists
+    if search_terms(&state.query).is_empty() {
+        sort_for_board(app, &mut output);
+    }
+    return output;
+}
+
+//Applies the sort remembered for the current board, if any
+pub fn sort_for_board(app : &app::App, items : &mut [objects::Item]) {
+    let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+    if let Some(item_sort) = &board_meta.sort {
+        sort::sort_items(items, item_sort, &board_meta);
+    }
+}
+
+//Monday colors come back as hex strings, e.g. "#579bfc"
+pub fn hex_to_color(hex: &str) -> Color {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() != 6 {
+        return Color::White;
     }
+    match (
+        u8::from_str_radix(&hex[0..2], 16),
+        u8::from_str_radix(&hex[2..4], 16),
+        u8::from_str_radix(&hex[4..6], 16),
+    ) {
+        (Ok(r), Ok(g), Ok(b)) => Color::Rgb(r, g, b),
+        _ => Color::White,
+    }
+}
 
-    //Check for filter by assigned
-    if app.f != KeyCode::Null {
-        match app.f {
-            KeyCode::F(2) => {
-                output = output.iter().filter(|item| {
-                    item.subscribers.iter().any(|sub| sub.id == app.current_user.id.clone())
-                }).cloned().collect::<Vec<objects::Item>>(); 
-            }, 
-            _ => {}
+//Column types shown in the item table when a board has no configured columns
+const DEFAULT_TABLE_COLUMN_TYPES: [&str; 4] = ["color", "multiple-person", "date", "numeric"];
+
+//Columns (id, title) to show in the item table for a board, in display order
+pub fn table_columns(board_meta: &cache::BoardMeta, items: &[objects::Item]) -> Vec<(String, String)> {
+    //Every column any item has, in the order they're first seen
+    let mut all_columns: Vec<(String, String, String)> = Vec::new();
+    for cv in items.iter().flat_map(|item| item.column_values.iter()) {
+        if !all_columns.iter().any(|(id, _, _)| *id == cv.id) {
+            all_columns.push((cv.id.clone(), cv.title.clone(), cv.type_.clone()));
         }
     }
-    return output;
+    pick_table_columns(board_meta, &all_columns)
+}
+
+//Columns (id, title) the item table shows out of a board's (id, title, type) columns
+pub fn pick_table_columns(board_meta: &cache::BoardMeta, all_columns: &[(String, String, String)]) -> Vec<(String, String)> {
+    if board_meta.visible_columns.is_empty() {
+        all_columns
+            .iter()
+            .filter(|(_, _, type_)| DEFAULT_TABLE_COLUMN_TYPES.contains(&type_.as_str()))
+            .map(|(id, title, _)| (id.clone(), title.clone()))
+            .collect()
+    } else {
+        board_meta
+            .visible_columns
+            .iter()
+            .filter_map(|id| all_columns.iter().find(|(c, _, _)| c == id))
+            .map(|(id, title, _)| (id.clone(), title.clone()))
+            .collect()
+    }
 }
@@ -1,30 +1,57 @@
 use super::app;
+use super::bulk;
 use super::components;
+use super::dashboard;
+use super::filter;
+use super::fuzzy;
+use super::keymap;
+use super::mouse;
 use super::objects;
 use super::queries;
+use super::router::{self, View};
+use super::sort;
 use super::utils;
 use super::cache; 
 
 use crossterm::event::{KeyCode, KeyEvent};
-use std::io;
 use tui::{
-    backend::CrosstermBackend,
-    layout::{Alignment, Constraint, Direction, Layout},
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     terminal::Frame,
     text::{Span, Spans},
-    widgets::{Block, BorderType, Borders, Cell, List, ListItem, Paragraph, Row, Table, Wrap},
+    widgets::{Block, BorderType, Borders, Cell, Gauge, List, ListItem, Paragraph, Row, Table, TableState, Wrap},
 };
 use serde::{Serialize, Deserialize}; 
 
 //Menu enum
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MenuItem {
     Home,
+    QuickSwitch, 
     Boards,
+    BoardOptions, 
+    NewBoard, 
+    DuplicateBoard, 
+    BoardSubscribers, 
+    BoardSettings, 
+    FindItem, 
+    IndexSearch, 
     Groups, 
     GroupsForMove, 
+    GroupOptions, 
+    NewGroup, 
+    RenameGroup, 
+    DuplicateGroup, 
     Items,
+    BulkOptions, 
+    BulkProgress, 
+    ItemTable, 
+    TableColumns, 
+    SortOptions, 
+    FilterOptions, 
+    ViewOptions, 
+    SaveView, 
     ItemDetail,
     ItemOptions,
     ItemUpdate,
@@ -35,14 +62,75 @@ pub enum MenuItem {
     StatusOptions, 
 }
 
+impl MenuItem {
+    //Variant names, as used to key per-view settings like the keymap
+    pub fn names() -> &'static [&'static str] {
+        &[
+            "Home",
+            "QuickSwitch",
+            "Boards",
+            "BoardOptions",
+            "NewBoard",
+            "DuplicateBoard",
+            "BoardSubscribers",
+            "BoardSettings",
+            "FindItem",
+            "IndexSearch",
+            "Groups",
+            "GroupsForMove",
+            "GroupOptions",
+            "NewGroup",
+            "RenameGroup",
+            "DuplicateGroup",
+            "Items",
+            "BulkOptions",
+            "BulkProgress",
+            "ItemTable",
+            "TableColumns",
+            "SortOptions",
+            "FilterOptions",
+            "ViewOptions",
+            "SaveView",
+            "ItemDetail",
+            "ItemOptions",
+            "ItemUpdate",
+            "NewItem",
+            "UserOptions",
+            "UserColumnOptions",
+            "StatusColumnOptions",
+            "StatusOptions",
+        ]
+    }
+}
+
 impl From<MenuItem> for usize {
     fn from(input: MenuItem) -> usize {
         match input {
             MenuItem::Home => 0,
+            MenuItem::QuickSwitch => 0, 
             MenuItem::Boards => 1,
+            MenuItem::BoardOptions => 1, 
+            MenuItem::NewBoard => 1, 
+            MenuItem::DuplicateBoard => 1, 
+            MenuItem::BoardSubscribers => 1, 
+            MenuItem::BoardSettings => 1, 
+            MenuItem::FindItem => 1, 
+            MenuItem::IndexSearch => 1, 
             MenuItem::Groups => 2,
             MenuItem::GroupsForMove => 4, 
+            MenuItem::GroupOptions => 2, 
+            MenuItem::NewGroup => 2, 
+            MenuItem::RenameGroup => 2, 
+            MenuItem::DuplicateGroup => 2, 
             MenuItem::Items => 3,
+            MenuItem::BulkOptions => 3, 
+            MenuItem::BulkProgress => 3, 
+            MenuItem::ItemTable => 3, 
+            MenuItem::TableColumns => 3, 
+            MenuItem::SortOptions => 3, 
+            MenuItem::FilterOptions => 3, 
+            MenuItem::ViewOptions => 3, 
+            MenuItem::SaveView => 3, 
             MenuItem::NewItem => 4, 
             MenuItem::ItemDetail => 4,
             MenuItem::ItemOptions => 4,
@@ -55,39 +143,152 @@ impl From<MenuItem> for usize {
     }
 }
 
+//Help bar entries for keymap actions of the active view, with the keys they're bound to.
+//A description replaces the action's own, for actions that do something specific here.
+fn help_bar(app: &app::App, actions: &[(&str, Option<&str>)]) -> Vec<(String, String)> {
+    let view = format!("{:?}", app.active_menu_item);
+    actions
+        .iter()
+        .filter_map(|(action, description)| {
+            let (keys, own) = app.keymap.describe(&view, action)?;
+            Some((keys, description.map(String::from).unwrap_or(own)))
+        })
+        .collect()
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct Home;
 
 impl Home {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &app::App) {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
         //Default chunks, search, and menu
-        let chunks = components::get_default_chunks(&rect);
-        let menu_block = components::get_menu_block(&app);
-
-        //Home paragraph
-        let home_block = Paragraph::new(vec![
-            Spans::from(vec![Span::raw("")]),
-            Spans::from(vec![Span::raw("Welcome")]),
-            Spans::from(vec![Span::raw("")]),
-            Spans::from(vec![Span::raw("")]),
-            Spans::from(vec![Span::styled(
-                "Monday-CLI",
-                Style::default().fg(Color::LightBlue),
-            )]),
-            Spans::from(vec![Span::raw("")]),
-        ])
-        .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::White))
-                .title("Home")
-                .border_type(BorderType::Plain),
+        let chunks = components::get_help_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+        let help_block = components::get_help_block(app);
+
+        let title = match (&app.dashboard, &app.dashboard_error) {
+            (Some(items), Some(error)) => format!("My Work ({} items, failed to load {})", items.len(), error),
+            (None, Some(error)) => format!("My Work (failed to load: {})", error),
+            (None, None) => String::from("My Work (loading...)"),
+            (Some(items), None) => format!("My Work ({} items)", items.len()),
+        };
+        let dashboard_block = Block::default()
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::White))
+            .title(title)
+            .border_type(BorderType::Plain);
+
+        //Section headers, then each item with its breadcrumb and due date. Overdue items are red.
+        let today = filter::today();
+        let rows = Home::rows(app);
+        let list_items: Vec<ListItem> = rows
+            .iter()
+            .map(|row| match row {
+                dashboard::Row::Header(title, count) => ListItem::new(Span::styled(
+                    format!("{} ({})", title, count),
+                    Style::default().fg(Color::LightBlue).add_modifier(Modifier::BOLD),
+                )),
+                dashboard::Row::Item(item) => {
+                    let due = item
+                        .column_values
+                        .iter()
+                        .find(|cv| cv.type_ == "date")
+                        .map(|cv| cv.text.clone())
+                        .unwrap_or_default();
+                    let name_style = if dashboard::is_overdue(item, today) {
+                        Style::default().fg(Color::LightRed)
+                    } else {
+                        Style::default()
+                    };
+                    ListItem::new(Spans::from(vec![
+                        Span::styled(format!("  {}", item.name), name_style),
+                        Span::styled(
+                            format!("  {} › {}", item.board.name, item.group.title),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                        Span::styled(format!("  {}", due), name_style),
+                    ]))
+                }
+            })
+            .collect();
+
+        let list_len = list_items.len();
+        let dashboard_list = List::new(list_items).block(dashboard_block).highlight_style(
+            Style::default()
+                .bg(Color::Yellow)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
         );
 
         //Render components
-        rect.render_widget(menu_block, chunks[0]);
-        rect.render_widget(home_block, chunks[1]);
+        rect.render_widget(help_block, chunks[1]);
+        components::render_list(rect, dashboard_list, chunks[2], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    fn rows(app: &app::App) -> Vec<dashboard::Row> {
+        let today = filter::today();
+        match &app.dashboard {
+            Some(items) => dashboard::rows(items, app.dashboard_by_due, today, &app.cache),
+            None => Vec::new(),
+        }
+    }
+
+    //Loads the dashboard the first time Home is shown, after the loading frame has been drawn
+    pub fn tick(self, app: &mut app::App) {
+        if let MenuItem::Home = app.active_menu_item {
+            if app.dashboard.is_none() && app.dashboard_error.is_none() {
+                self.refresh(app);
+            }
+        }
+    }
+
+    pub fn refresh(self, app: &mut app::App) {
+        let (items, error) = dashboard::load(app);
+        app.dashboard = Some(items);
+        app.dashboard_error = error;
+        self.select_first(app);
+    }
+
+    fn select_first(self, app: &mut app::App) {
+        let first = Home::rows(app)
+            .iter()
+            .position(|row| matches!(row, dashboard::Row::Item(_)));
+        app.list_state.select(Some(first.unwrap_or(0)));
+    }
+
+    //Moves to the next item row in the given direction, skipping section headers
+    fn step(self, app: &mut app::App, forward: bool) {
+        let rows = Home::rows(app);
+        if rows.is_empty() {
+            return;
+        }
+        let mut index = app.list_state.selected().unwrap_or(0).min(rows.len() - 1);
+        for _ in 0..rows.len() {
+            index = if forward {
+                (index + 1) % rows.len()
+            } else {
+                (index + rows.len() - 1) % rows.len()
+            };
+            if let dashboard::Row::Item(_) = rows[index] {
+                app.list_state.select(Some(index));
+                return;
+            }
+        }
+    }
+
+    pub fn keyup(self, app: &mut app::App) {
+        self.step(app, false);
+    }
+
+    pub fn keydown(self, app: &mut app::App) {
+        self.step(app, true);
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        let rows = Home::rows(app);
+        if let Some(dashboard::Row::Item(item)) = app.list_state.selected().and_then(|i| rows.get(i)) {
+            open_search_result(app, *item.clone());
+        }
     }
 
     pub fn keyright(self, app: &mut app::App) {
@@ -101,32 +302,72 @@ impl Home {
     }
 
     pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        app.key_input = Vec::new();
         match event.code {
             KeyCode::Left => self.keyleft(app),
             KeyCode::Right => self.keyright(app),
+            KeyCode::Up => self.keyup(app),
+            KeyCode::Down => self.keydown(app),
+            KeyCode::Enter => self.keyenter(app),
+            KeyCode::F(2) => {
+                app.dashboard_by_due = !app.dashboard_by_due;
+                self.select_first(app);
+            }
+            KeyCode::F(5) => self.refresh(app),
             _ => {}
         }
     }
 }
 
+impl<B: Backend> View<B> for Home {
+    fn title(&self) -> &'static str {
+        "My Work"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(
+            app,
+            &[
+                ("select", Some("Open Item")),
+                ("group_by", Some(if app.dashboard_by_due { "Group by Status" } else { "Group by Due Date" })),
+                ("refresh", None),
+            ],
+        )
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        Home::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn tick(&self, app: &mut app::App) {
+        Home::tick(*self, app)
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct BoardList;
 
 impl BoardList {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &app::App) {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
         //Default chunks, search, and menu
-        let chunks = components::get_default_chunks(&rect);
+        let chunks = components::get_help_chunks(&rect);
+        components::render_menu(rect, chunks[0], app);
         let search_block = components::get_search_block(&app);
-        let menu_block = components::get_menu_block(&app);
+        let help_block = components::get_help_block(&app); 
 
         //Filter boards
         let filtered = utils::filter_boards(&app.boards, &app.key_input);
 
-        //Board chunks
+        //Board chunks; the detail pane is dropped on narrow terminals
+        let list_share = if components::is_narrow(rect.size()) { 100 } else { 40 };
         let board_chunks = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
-            .split(chunks[1]);
+            .constraints([Constraint::Percentage(list_share), Constraint::Percentage(100 - list_share)].as_ref())
+            .split(chunks[2]);
 
         //Board block
         let board_block = Block::default()
@@ -136,17 +377,22 @@ impl BoardList {
             .border_type(BorderType::Plain);
 
         //Create list items
+        let query = app.key_input.iter().collect::<String>();
         let list_items: Vec<ListItem> = filtered
             .iter()
-            .map(|x| ListItem::new(x.name.to_owned()))
+            .map(|x| {
+                //Favorites are starred
+                let star = if app.cache.get_board_meta(x.id.clone()).favorite { "★ " } else { "  " };
+                let mut spans = vec![Span::styled(star, Style::default().fg(Color::Yellow))];
+                spans.extend(components::highlight_matches(&x.name, &query));
+                ListItem::new(Spans::from(spans))
+            })
             .collect();
 
-        let selected_board = filtered
-            .get(
-                app.list_state
-                    .selected()
-                    .expect("there is always a selected board"),
-            )
+        let selected_board = app
+            .list_state
+            .selected()
+            .and_then(|i| filtered.get(i))
             .unwrap_or(&objects::Board {
                 name: "".to_owned(),
                 id: "".to_owned(),
@@ -154,6 +400,7 @@ impl BoardList {
             .clone();
 
         //Create list component
+        let list_len = list_items.len();
         let board_list = List::new(list_items).block(board_block).highlight_style(
             Style::default()
                 .bg(Color::Yellow)
@@ -186,32 +433,12 @@ impl BoardList {
         .widths(&[Constraint::Percentage(50), Constraint::Percentage(50)]);
 
         //Render components
-        rect.render_widget(menu_block, chunks[0]);
-        rect.render_stateful_widget(board_list, board_chunks[0], &mut app.list_state.clone());
-        rect.render_widget(board_detail, board_chunks[1]);
-        rect.render_widget(search_block, chunks[2]);
-    }
-
-    pub fn keyup(self, app: &mut app::App) {
-        if let Some(selected) = app.list_state.selected() {
-            let amount_boards = utils::filter_boards(&app.boards, &app.key_input).len();
-            if selected > 0 {
-                app.list_state.select(Some(selected - 1));
-            } else {
-                app.list_state.select(Some(amount_boards - 1));
-            }
-        }
-    }
-
-    pub fn keydown(self, app: &mut app::App) {
-        if let Some(selected) = app.list_state.selected() {
-            let list_length = utils::filter_boards(&app.boards, &app.key_input).len();
-            if selected >= list_length - 1 {
-                app.list_state.select(Some(0));
-            } else {
-                app.list_state.select(Some(selected + 1));
-            }
+        rect.render_widget(help_block, chunks[1]); 
+        if board_chunks[1].width > 0 {
+            rect.render_widget(board_detail, board_chunks[1]);
         }
+        rect.render_widget(search_block, chunks[3]);
+        components::render_list(rect, board_list, board_chunks[0], list_len, &mut app.list_state, &mut app.mouse);
     }
 
     pub fn keyright(self, app: &mut app::App) {
@@ -226,273 +453,2783 @@ impl BoardList {
 
     pub fn keyenter(self, app: &mut app::App) {
         let board_filtered = utils::filter_boards(&app.boards, &app.key_input);
-        app.active_menu_item = MenuItem::Groups;
-        let selected_board = board_filtered
-            .get(app.list_state.selected().unwrap())
-            .unwrap()
-            .clone();
-        app.board_detail = selected_board.clone(); 
-        app.groups = queries::group_list(&app.client, selected_board.id); 
-        app.key_input = Vec::new();
-        app.list_state.select(Some(0));
+        if let Some(board) = app.list_state.selected().and_then(|i| board_filtered.get(i)) {
+            open_board(app, board.clone());
+        }
     }
 
     pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
         match event.code {
-            KeyCode::Up => self.keyup(app),
-            KeyCode::Down => self.keydown(app),
             KeyCode::Left => self.keyleft(app),
             KeyCode::Right => self.keyright(app),
             KeyCode::Enter => self.keyenter(app),
+            KeyCode::F(3) => { //Create Board
+                app.key_input = Vec::new();
+                app.board_workspace = Vec::new();
+                app.editing_workspace = false;
+                app.active_menu_item = MenuItem::NewBoard;
+            }
+            KeyCode::F(5) => { //Find Items Across All Boards
+                app.key_input = Vec::new();
+                app.find_results = Vec::new();
+                app.find_query = String::new();
+                app.find_error = None;
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::FindItem;
+            }
+            KeyCode::F(7) => { //Star or Unstar the Selected Board
+                let filtered = utils::filter_boards(&app.boards, &app.key_input);
+                if let Some(board) = app.list_state.selected().and_then(|i| filtered.get(i)) {
+                    let mut board_meta = app.cache.get_board_meta(board.id.clone());
+                    board_meta.id = board.id.clone();
+                    board_meta.favorite = !board_meta.favorite;
+                    app.cache.update_board_meta(board_meta);
+                    app.write_cache();
+                }
+            }
+            KeyCode::F(6) => { //Search the Local Index
+                app.key_input = Vec::new();
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::IndexSearch;
+            }
+            KeyCode::F(4) => { //Options for Selected Board
+                let filtered = utils::filter_boards(&app.boards, &app.key_input);
+                if let Some(board) = app.list_state.selected().and_then(|i| filtered.get(i)) {
+                    app.set_board(board.clone());
+                    app.key_input = Vec::new();
+                    app.list_state.select(Some(0));
+                    app.active_menu_item = MenuItem::BoardOptions;
+                }
+            }
             _ => {}
         }
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-pub struct GroupList;
+impl<B: Backend> View<B> for BoardList {
+    fn title(&self) -> &'static str {
+        "Boards"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(
+            app,
+            &[
+                ("create_board", None),
+                ("board_options", None),
+                ("find_item", None),
+                ("search_index", None),
+                ("star_board", None),
+                (keymap::QUICK_SWITCH, None),
+            ],
+        )
+    }
 
-impl GroupList {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &mut app::App) {
-        //Default chunks, search, and menu
-        let chunks = components::get_default_chunks(&rect);
-        let search_block = components::get_search_block(&app);
-        let menu_block = components::get_menu_block(&app);
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        BoardList::render(rect, app)
+    }
 
-        //Filter items
-        let filtered = utils::filter_groups(&app);
-        let board_block = Block::default()
-            .borders(Borders::ALL)
-            .style(Style::default().fg(Color::White))
-            .title("Group")
-            .border_type(BorderType::Plain);
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
 
-        //Create item list
-        let list_items: Vec<ListItem> = filtered
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(utils::filter_boards(&app.boards, &app.key_input).len())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct FindItem;
+
+impl FindItem {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_help_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+        let help_block = components::get_help_block(app);
+
+        //Each result with its board and group breadcrumb
+        let crumb = Style::default().fg(Color::DarkGray);
+        let items = app
+            .find_results
             .iter()
-            .map(|x| ListItem::new(x.title.to_owned()))
-            .collect();
+            .map(|item| {
+                ListItem::new(Spans::from(vec![
+                    Span::styled(format!("{} › {} › ", item.board.name, item.group.title), crumb),
+                    Span::raw(item.name.clone()),
+                ]))
+            })
+            .collect::<Vec<ListItem>>();
+        let title = match &app.find_error {
+            Some(error) => format!("Search failed: {}", error),
+            None if app.find_query.is_empty() => String::from("Results"),
+            None => format!("{} results for \"{}\"", app.find_results.len(), app.find_query),
+        };
+        let list_len = items.len();
+        let result_list = List::new(items)
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::Yellow)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let input = components::get_input_block(app, "Find: ", "Find Item in All Boards");
 
-        let item_list = List::new(list_items).block(board_block).highlight_style(
-            Style::default()
-                .bg(Color::Yellow)
-                .fg(Color::Black)
-                .add_modifier(Modifier::BOLD),
-        );
+        rect.render_widget(help_block, chunks[1]);
+        components::render_list(rect, result_list, chunks[2], list_len, &mut app.list_state, &mut app.mouse);
+        rect.render_widget(input, chunks[3]);
+    }
 
-        //Render components
-        rect.render_widget(menu_block, chunks[0]);
-        rect.render_stateful_widget(item_list, chunks[1], &mut app.list_state.clone());
-        rect.render_widget(search_block, chunks[2]);
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = Vec::new();
+        app.list_state.select(Some(0));
+        app.active_menu_item = MenuItem::Boards;
     }
 
-    pub fn keyup(self, app: &mut app::App) {
-        if let Some(selected) = app.list_state.selected() {
-            let list_length = utils::filter_groups(app).len();
-            if selected > 0 {
-                app.list_state.select(Some(selected - 1));
-            } else {
-                app.list_state.select(Some(list_length - 1));
+    //Searches every loaded board by item name and by the status and people columns picked for it
+    pub fn search(self, app: &mut app::App) {
+        let query: String = app.key_input.iter().collect();
+        if query.trim().is_empty() {
+            return;
+        }
+        if app.boards.is_empty() {
+            app.boards = app.api.board_list();
+        }
+        let targets = app
+            .boards
+            .iter()
+            .map(|board| {
+                let board_meta = app.cache.get_board_meta(board.id.clone());
+                queries::SearchTarget {
+                    board_id: board.id.clone(),
+                    column_ids: vec![board_meta.status_column_id, board_meta.user_column_id]
+                        .into_iter()
+                        .filter(|id| !id.is_empty())
+                        .collect(),
+                }
+            })
+            .collect::<Vec<queries::SearchTarget>>();
+        match app.api.search_items_across_boards(&targets, query.trim()) {
+            Ok(results) => {
+                app.find_results = results;
+                app.find_error = None;
+            }
+            Err(error) => {
+                app.find_results = Vec::new();
+                app.find_error = Some(error);
             }
         }
+        app.find_query = query;
+        app.list_state.select(Some(0));
     }
 
-    pub fn keydown(self, app: &mut app::App) {
-        if let Some(selected) = app.list_state.selected() {
-            let list_length = utils::filter_groups(app).len();
-            if selected >= list_length - 1 {
-                app.list_state.select(Some(0));
-            } else {
-                app.list_state.select(Some(selected + 1));
+    pub fn open(self, app: &mut app::App) {
+        if let Some(item) = app.list_state.selected().and_then(|i| app.find_results.get(i)) {
+            open_search_result(app, item.clone());
+        }
+    }
+
+    //Enter searches when the query changed since the last search, otherwise opens the selected result
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Right => self.open(app),
+            KeyCode::Enter => {
+                let query: String = app.key_input.iter().collect();
+                if query != app.find_query || app.find_results.is_empty() {
+                    self.search(app);
+                } else {
+                    self.open(app);
+                }
             }
+            _ => {}
         }
     }
+}
 
-    pub fn keyright(self, app: &mut app::App) {
-        app.active_menu_item = MenuItem::Items;
-        app.key_input = vec![]; 
+impl<B: Backend> View<B> for FindItem {
+    fn title(&self) -> &'static str {
+        "Find Item"
     }
 
-    pub fn keyleft(self, app: &mut app::App) {
-        app.active_menu_item = MenuItem::Boards;
-        app.key_input = vec![]; 
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(app, &[("select", Some("Search / Open")), ("open", Some("Open Item")), ("back", None)])
     }
 
-    pub fn keyenter(self, app: &mut app::App) {
-        let filtered = utils::filter_groups(&app);
-        app.active_menu_item = MenuItem::Items;
-        let selected_group = filtered
-            .get(app.list_state.selected().unwrap())
-            .unwrap()
-            .clone();
-        app.group_detail = selected_group.clone(); 
-        app.items = queries::item_list(&app.client, app.board_detail.id.clone(), selected_group.id);
-        app.key_input = Vec::new();
-        app.list_state.select(Some(0));
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        FindItem::render(rect, app)
     }
 
-    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
-        match event.code {
-            KeyCode::Up => self.keyup(app),
-            KeyCode::Down => self.keydown(app),
-            KeyCode::Left => self.keyleft(app),
-            KeyCode::Right => self.keyright(app),
-            KeyCode::Enter => self.keyenter(app),
-            _ => {}
-        }
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(app.find_results.len())
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-pub struct GroupListForMove;
+//Adds the current board, group or item to the recent list used by the quick switcher
+fn remember(app: &mut app::App, kind: cache::RecentKind) {
+    let (group_id, group_title) = match kind {
+        cache::RecentKind::Board => (String::new(), String::new()),
+        _ => (app.group_detail.id.clone(), app.group_detail.title.clone()),
+    };
+    let (item_id, item_name) = match kind {
+        cache::RecentKind::Item => (app.item_detail.id.clone(), app.item_detail.name.clone()),
+        _ => (String::new(), String::new()),
+    };
+    app.cache.add_recent(cache::Recent {
+        kind,
+        board: app.board_detail.clone(),
+        group_id,
+        group_title,
+        item_id,
+        item_name,
+    });
+    app.write_cache();
+}
 
-impl GroupListForMove {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &mut app::App) {
-        GroupList::render(rect, app); 
+fn open_board(app: &mut app::App, board: objects::Board) {
+    app.set_board(board);
+    detect_board_columns(app);
+    app.groups = app.api.group_list(app.board_detail.id.clone());
+    remember(app, cache::RecentKind::Board);
+    app.key_input = Vec::new();
+    app.list_state.select(Some(0));
+    app.active_menu_item = MenuItem::Groups;
+}
+
+//Opens a group of the current board, landing on the board's default view
+fn open_group(app: &mut app::App, group: objects::Group) {
+    app.group_detail = group;
+    app.fetch_items(app.board_detail.id.clone(), app.group_detail.id.clone());
+    remember(app, cache::RecentKind::Group);
+    app.selected_items = Vec::new();
+    app.key_input = Vec::new();
+    app.list_state.select(Some(0));
+    app.active_menu_item = MenuItem::Items;
+
+    let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+    if let Some(view) = board_meta.default_view.as_ref().and_then(|name| board_meta.get_view(name)) {
+        apply_view(app, view.clone());
     }
+}
 
-    pub fn keyup(self, app: &mut app::App) {
-        GroupList.keyup(app); 
+//Opens a menu tab the way keyboard navigation would, loading what it shows.
+//Tabs for a board, group or item that hasn't been opened yet are ignored.
+pub fn open_tab(app: &mut app::App, menu_item: MenuItem) {
+    match menu_item {
+        MenuItem::Groups if !app.board_detail.id.is_empty() => open_board(app, app.board_detail.clone()),
+        MenuItem::Items if !app.group_detail.id.is_empty() => open_group(app, app.group_detail.clone()),
+        MenuItem::ItemDetail if !app.item_detail.id.is_empty() => {
+            app.fetch_item_detail(app.item_detail.id.clone());
+            app.key_input = Vec::new();
+            app.list_state.select(Some(0));
+            app.active_menu_item = MenuItem::ItemDetail;
+        }
+        MenuItem::Home | MenuItem::Boards => {
+            app.key_input = Vec::new();
+            app.list_state.select(Some(0));
+            app.active_menu_item = menu_item;
+        }
+        _ => {}
     }
+}
 
-    pub fn keydown(self, app: &mut app::App) {
-        GroupList.keydown(app); 
+//Picks the board's status and people columns from its schema the first time it's opened
+fn detect_board_columns(app: &mut app::App) {
+    let mut board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+    if !board_meta.status_column_id.is_empty() && !board_meta.user_column_id.is_empty() {
+        return;
+    }
+    let columns = app.api.column_schema(app.board_detail.id.clone());
+    if board_meta.detect_columns(&columns) {
+        board_meta.id = app.board_detail.id.clone();
+        app.cache.update_board_meta(board_meta);
+        app.write_cache();
     }
+}
 
-    pub fn keyright(self, app: &mut app::App) {
-        app.active_menu_item = MenuItem::Home;
-        app.key_input = vec![]; 
+//Loads a search result's board and group so going back from the detail lands in its item list
+fn open_search_result(app: &mut app::App, item: objects::Item) {
+    let board = app
+        .boards
+        .iter()
+        .find(|board| board.id == item.board.id)
+        .cloned()
+        .unwrap_or_else(|| item.board.clone());
+    app.set_board(board);
+    detect_board_columns(app);
+    app.groups = app.api.group_list(item.board.id.clone());
+    app.group_detail = app
+        .groups
+        .iter()
+        .find(|group| group.id == item.group.id)
+        .cloned()
+        .unwrap_or_else(|| item.group.clone());
+    app.fetch_items(item.board.id.clone(), item.group.id.clone());
+    app.fetch_item_detail(item.id);
+    remember(app, cache::RecentKind::Item);
+    app.selected_items = Vec::new();
+    app.key_input = Vec::new();
+    app.list_state.select(Some(0));
+    app.active_menu_item = MenuItem::ItemDetail;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct QuickSwitch;
+
+impl QuickSwitch {
+    //Favorite boards first, then recent boards, groups and items, fuzzy filtered by the input
+    fn entries(app: &app::App) -> Vec<(bool, cache::Recent)> {
+        let mut entries: Vec<(bool, cache::Recent)> = app
+            .cache
+            .boards
+            .iter()
+            .filter(|meta| meta.favorite)
+            .filter_map(|meta| app.boards.iter().find(|board| board.id == meta.id))
+            .map(|board| {
+                (
+                    true,
+                    cache::Recent {
+                        kind: cache::RecentKind::Board,
+                        board: board.clone(),
+                        group_id: String::new(),
+                        group_title: String::new(),
+                        item_id: String::new(),
+                        item_name: String::new(),
+                    },
+                )
+            })
+            .collect();
+        for recent in app.cache.recent.iter() {
+            if !entries.iter().any(|(_, entry)| entry == recent) {
+                entries.push((false, recent.clone()));
+            }
+        }
+        let query: String = app.key_input.iter().collect();
+        if query.trim().is_empty() {
+            return entries;
+        }
+        let mut scored = entries
+            .into_iter()
+            .filter_map(|entry| {
+                let text = format!("{} {}", entry.1.title(), entry.1.breadcrumb());
+                fuzzy::fuzzy_match(&query, &text).map(|found| (found.score, entry))
+            })
+            .collect::<Vec<(i64, (bool, cache::Recent))>>();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
     }
 
-    pub fn keyleft(self, app: &mut app::App) {
-        app.active_menu_item = MenuItem::ItemDetail; 
-        app.key_input = vec![]; 
+    pub fn open(self, app: &mut app::App) {
+        if let MenuItem::QuickSwitch = app.active_menu_item {
+            return;
+        }
+        app.switch_return = app.active_menu_item;
+        app.key_input = Vec::new();
+        app.list_state.select(Some(0));
+        app.active_menu_item = MenuItem::QuickSwitch;
     }
 
-    pub fn keyenter(self, app: &mut app::App) {
-        let filtered = utils::filter_groups(&app);
-        app.active_menu_item = MenuItem::ItemDetail;
-        let selected_group = filtered
-            .get(app.list_state.selected().unwrap())
-            .unwrap()
-            .clone();
-        queries::move_item(&app, selected_group.id.clone()); 
-        app.item_detail = queries::item_detail(&app.client, app.item_detail.id.clone()); 
-        app.group_detail = selected_group.clone(); 
-        app.items = queries::item_list(&app.client, app.board_detail.id.clone(), selected_group.id);
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_help_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+        let help_block = components::get_help_block(app);
+
+        let query: String = app.key_input.iter().collect();
+        let items = QuickSwitch::entries(app)
+            .iter()
+            .map(|(starred, entry)| {
+                let kind = match entry.kind {
+                    cache::RecentKind::Board => "Board",
+                    cache::RecentKind::Group => "Group",
+                    cache::RecentKind::Item => "Item ",
+                };
+                let mut spans = vec![
+                    Span::styled(if *starred { "★ " } else { "  " }, Style::default().fg(Color::Yellow)),
+                    Span::styled(format!("{}  ", kind), Style::default().fg(Color::DarkGray)),
+                ];
+                spans.extend(components::highlight_matches(entry.title(), &query));
+                let breadcrumb = entry.breadcrumb();
+                if !breadcrumb.is_empty() {
+                    spans.push(Span::styled(format!("  {}", breadcrumb), Style::default().fg(Color::DarkGray)));
+                }
+                ListItem::new(Spans::from(spans))
+            })
+            .collect::<Vec<ListItem>>();
+        let list_len = items.len();
+        let entry_list = List::new(items)
+            .block(Block::default().title("Favorites and Recent").borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::Yellow)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let input = components::get_input_block(app, "Go to: ", "Quick Switcher");
+
+        rect.render_widget(help_block, chunks[1]);
+        components::render_list(rect, entry_list, chunks[2], list_len, &mut app.list_state, &mut app.mouse);
+        rect.render_widget(input, chunks[3]);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
         app.key_input = Vec::new();
         app.list_state.select(Some(0));
+        app.active_menu_item = app.switch_return;
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        let entries = QuickSwitch::entries(app);
+        let entry = match app.list_state.selected().and_then(|i| entries.get(i)) {
+            Some((_, entry)) => entry.clone(),
+            None => return,
+        };
+        match entry.kind {
+            cache::RecentKind::Board => open_board(app, entry.board),
+            cache::RecentKind::Group => {
+                open_board(app, entry.board.clone());
+                let group = app
+                    .groups
+                    .iter()
+                    .find(|group| group.id == entry.group_id)
+                    .cloned()
+                    .unwrap_or_else(|| objects::Group {
+                        id: entry.group_id.clone(),
+                        title: entry.group_title.clone(),
+                        color: String::new(),
+                    });
+                open_group(app, group);
+            }
+            cache::RecentKind::Item => {
+                let mut item = objects::Item::new();
+                item.id = entry.item_id;
+                item.name = entry.item_name;
+                item.board = entry.board;
+                item.group.id = entry.group_id;
+                item.group.title = entry.group_title;
+                open_search_result(app, item);
+            }
+        }
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter | KeyCode::Right => self.keyenter(app),
+            KeyCode::Char(_) | KeyCode::Backspace => app.list_state.select(Some(0)),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for QuickSwitch {
+    fn title(&self) -> &'static str {
+        "Quick Switcher"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(app, &[("select", Some("Go To")), ("back", None)])
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        QuickSwitch::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(QuickSwitch::entries(app).len())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct IndexSearch;
+
+impl IndexSearch {
+    //Results are capped so a one letter query stays instant
+    const MAX_RESULTS: usize = 200;
+
+    fn results(app: &app::App) -> Vec<objects::Item> {
+        let query: String = app.key_input.iter().collect();
+        app.index
+            .search(&query)
+            .into_iter()
+            .take(Self::MAX_RESULTS)
+            .map(|indexed| indexed.to_item())
+            .collect()
+    }
+
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_help_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+        let help_block = components::get_help_block(app);
+
+        let results = IndexSearch::results(app);
+        let crumb = Style::default().fg(Color::DarkGray);
+        let items = results
+            .iter()
+            .map(|item| {
+                ListItem::new(Spans::from(vec![
+                    Span::styled(format!("{} › {} › ", item.board.name, item.group.title), crumb),
+                    Span::raw(item.name.clone()),
+                ]))
+            })
+            .collect::<Vec<ListItem>>();
+        let title = format!("{} matches in {} indexed items", results.len(), app.index.len());
+        let list_len = items.len();
+        let result_list = List::new(items)
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::Yellow)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let input = components::get_input_block(app, "Search: ", "Search Local Index");
+
+        rect.render_widget(help_block, chunks[1]);
+        components::render_list(rect, result_list, chunks[2], list_len, &mut app.list_state, &mut app.mouse);
+        rect.render_widget(input, chunks[3]);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = Vec::new();
+        app.list_state.select(Some(0));
+        app.active_menu_item = MenuItem::Boards;
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        let results = IndexSearch::results(app);
+        if let Some(item) = app.list_state.selected().and_then(|i| results.get(i)) {
+            open_search_result(app, item.clone());
+        }
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter | KeyCode::Right => self.keyenter(app),
+            //Results change as the query is typed
+            KeyCode::Char(_) | KeyCode::Backspace => app.list_state.select(Some(0)),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for IndexSearch {
+    fn title(&self) -> &'static str {
+        "Search Index"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(app, &[("select", Some("Open Item")), ("back", None)])
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        IndexSearch::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(IndexSearch::results(app).len())
+    }
+}
+
+//Reload boards after a board mutation and return to the board list
+fn refresh_boards(app: &mut app::App) {
+    app.boards = app.api.board_list();
+    app.key_input = Vec::new();
+    app.list_state.select(Some(0));
+    app.active_menu_item = MenuItem::Boards;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct BoardOptions;
+
+impl BoardOptions {
+    const OPTIONS: [&'static str; 6] = [
+        "Duplicate Structure",
+        "Duplicate With Items",
+        "Duplicate With Items and Updates",
+        "Archive Board",
+        "Add Subscriber",
+        "Settings",
+    ];
+
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+
+        let items = Self::OPTIONS
+            .iter()
+            .enumerate()
+            .map(|(i, x)| components::option_item(app, i, x))
+            .collect::<Vec<ListItem>>();
+
+        let list_len = items.len();
+        let option_list = List::new(items)
+            .block(Block::default().title(app.board_detail.name.clone()).borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
+            .highlight_symbol(">>");
+
+        components::render_list(rect, option_list, chunks[1], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    pub fn keyright(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Home;
+        app.key_input = vec![]; 
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Boards;
+        app.key_input = vec![]; 
+        app.list_state.select(Some(0));
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        let duplicate_type = match app.list_state.selected().unwrap_or(0) {
+            0 => Some(objects::DuplicateBoardType::Structure),
+            1 => Some(objects::DuplicateBoardType::Pulses),
+            2 => Some(objects::DuplicateBoardType::PulsesAndUpdates),
+            _ => None,
+        };
+        if let Some(duplicate_type) = duplicate_type {
+            app.duplicate_type = duplicate_type;
+            app.key_input = app.board_detail.name.chars().collect();
+            app.active_menu_item = MenuItem::DuplicateBoard;
+            return;
+        }
+        match app.list_state.selected() {
+            Some(3) if app.confirm() => {
+                app.api.archive_board(app.board_detail.id.clone());
+                refresh_boards(app);
+            }
+            Some(4) => {
+                app.users = app.api.account_users();
+                app.key_input = Vec::new();
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::BoardSubscribers;
+            }
+            Some(5) => {
+                app.board_columns = app.api.column_schema(app.board_detail.id.clone());
+                app.key_input = Vec::new();
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::BoardSettings;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Right => self.keyright(app),
+            KeyCode::Enter => self.keyenter(app),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for BoardOptions {
+    fn title(&self) -> &'static str {
+        "Board Options"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        BoardOptions::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, _app: &app::App) -> Option<usize> {
+        Some(BoardOptions::OPTIONS.len())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct BoardSettings;
+
+#[derive(Debug, Clone, PartialEq)]
+enum BoardSetting {
+    StatusColumn,
+    PeopleColumn,
+    DefaultView,
+    Favorite,
+    Sort,
+    VisibleColumns,
+    //Column id of a configured table width
+    ColumnWidth(String),
+    Reset,
+}
+
+impl BoardSettings {
+    fn settings(board_meta: &cache::BoardMeta) -> Vec<BoardSetting> {
+        let mut settings = vec![
+            BoardSetting::StatusColumn,
+            BoardSetting::PeopleColumn,
+            BoardSetting::DefaultView,
+            BoardSetting::Favorite,
+            BoardSetting::Sort,
+            BoardSetting::VisibleColumns,
+        ];
+        let mut widths = board_meta.column_widths.keys().cloned().collect::<Vec<String>>();
+        widths.sort();
+        settings.extend(widths.into_iter().map(BoardSetting::ColumnWidth));
+        settings.push(BoardSetting::Reset);
+        settings
+    }
+
+    fn column_title(app: &app::App, column_id: &str) -> String {
+        match app.board_columns.iter().find(|c| c.id == column_id) {
+            Some(column) => column.title.clone(),
+            None if column_id.is_empty() => String::from("(none)"),
+            None => column_id.to_string(),
+        }
+    }
+
+    fn describe(app: &app::App, board_meta: &cache::BoardMeta, setting: &BoardSetting) -> (String, String) {
+        match setting {
+            BoardSetting::StatusColumn => (
+                String::from("Status Column"),
+                BoardSettings::column_title(app, &board_meta.status_column_id),
+            ),
+            BoardSetting::PeopleColumn => (
+                String::from("People Column"),
+                BoardSettings::column_title(app, &board_meta.user_column_id),
+            ),
+            BoardSetting::DefaultView => (
+                String::from("Default View"),
+                board_meta.default_view.clone().unwrap_or_else(|| String::from("(none)")),
+            ),
+            BoardSetting::Favorite => (
+                String::from("Favorite"),
+                String::from(if board_meta.favorite { "yes" } else { "no" }),
+            ),
+            BoardSetting::Sort => (
+                String::from("Sort"),
+                match &board_meta.sort {
+                    Some(item_sort) => format!(
+                        "{:?} {}",
+                        item_sort.key,
+                        if item_sort.descending { "▼" } else { "▲" }
+                    ),
+                    None => String::from("(none)"),
+                },
+            ),
+            BoardSetting::VisibleColumns => (
+                String::from("Table Columns"),
+                if board_meta.visible_columns.is_empty() {
+                    String::from("(defaults)")
+                } else {
+                    board_meta
+                        .visible_columns
+                        .iter()
+                        .map(|id| BoardSettings::column_title(app, id))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                },
+            ),
+            BoardSetting::ColumnWidth(id) => (
+                format!("Width of {}", BoardSettings::column_title(app, id)),
+                board_meta.column_width(id).to_string(),
+            ),
+            BoardSetting::Reset => (String::from("Reset All Settings"), String::new()),
+        }
+    }
+
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_help_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+        let help_block = components::get_help_block(app);
+        let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+
+        let items = BoardSettings::settings(&board_meta)
+            .iter()
+            .map(|setting| {
+                let (name, value) = BoardSettings::describe(app, &board_meta, setting);
+                if value.is_empty() {
+                    return ListItem::new(Span::styled(name, Style::default().fg(Color::LightRed)));
+                }
+                ListItem::new(Spans::from(vec![
+                    Span::raw(format!("{}: ", name)),
+                    Span::styled(value, Style::default().fg(Color::LightCyan)),
+                ]))
+            })
+            .collect::<Vec<ListItem>>();
+
+        let list_len = items.len();
+        let settings_list = List::new(items)
+            .block(Block::default().title(format!("{} Settings", app.board_detail.name)).borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
+            .highlight_symbol(">>");
+
+        rect.render_widget(help_block, chunks[1]);
+        components::render_list(rect, settings_list, chunks[2], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = Vec::new();
+        app.list_state.select(Some(0));
+        app.active_menu_item = MenuItem::BoardOptions;
+    }
+
+    //Moves to the next column of the matching type
+    fn next_column(columns: Vec<&objects::Column>, current: &str) -> String {
+        match columns.iter().position(|c| c.id == current) {
+            Some(index) => columns[(index + 1) % columns.len()].id.clone(),
+            None => columns.first().map(|c| c.id.clone()).unwrap_or_default(),
+        }
+    }
+
+    fn update_board_meta<F: FnOnce(&mut cache::BoardMeta, &[objects::Column])>(app: &mut app::App, f: F) {
+        let mut board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        board_meta.id = app.board_detail.id.clone();
+        f(&mut board_meta, &app.board_columns);
+        app.cache.update_board_meta(board_meta);
+        app.write_cache();
+    }
+
+    //Cycles or clears the selected setting
+    pub fn keyenter(self, app: &mut app::App) {
+        let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        let settings = BoardSettings::settings(&board_meta);
+        let setting = match app.list_state.selected().and_then(|i| settings.get(i)) {
+            Some(setting) => setting.clone(),
+            None => return,
+        };
+        match setting {
+            BoardSetting::StatusColumn => BoardSettings::update_board_meta(app, |meta, columns| {
+                let status_columns = columns.iter().filter(|c| c.is_status()).collect();
+                meta.status_column_id = BoardSettings::next_column(status_columns, &meta.status_column_id);
+            }),
+            BoardSetting::PeopleColumn => BoardSettings::update_board_meta(app, |meta, columns| {
+                let people_columns = columns.iter().filter(|c| c.is_people()).collect();
+                meta.user_column_id = BoardSettings::next_column(people_columns, &meta.user_column_id);
+            }),
+            //(none), then each saved view in turn
+            BoardSetting::DefaultView => BoardSettings::update_board_meta(app, |meta, _| {
+                let names = meta.views.iter().map(|v| v.name.clone()).collect::<Vec<String>>();
+                meta.default_view = match &meta.default_view {
+                    None => names.first().cloned(),
+                    Some(current) => match names.iter().position(|n| n == current) {
+                        Some(index) => names.get(index + 1).cloned(),
+                        None => names.first().cloned(),
+                    },
+                };
+            }),
+            BoardSetting::Favorite => BoardSettings::update_board_meta(app, |meta, _| meta.favorite = !meta.favorite),
+            BoardSetting::Sort => BoardSettings::update_board_meta(app, |meta, _| meta.sort = None),
+            BoardSetting::VisibleColumns => BoardSettings::update_board_meta(app, |meta, _| meta.visible_columns.clear()),
+            BoardSetting::ColumnWidth(id) => BoardSettings::update_board_meta(app, |meta, _| {
+                meta.column_widths.remove(&id);
+            }),
+            //Forget everything, then detect the columns again
+            BoardSetting::Reset => {
+                app.cache.remove_board_meta(&app.board_detail.id);
+                let mut board_meta = cache::BoardMeta::new();
+                board_meta.id = app.board_detail.id.clone();
+                board_meta.detect_columns(&app.board_columns);
+                app.cache.update_board_meta(board_meta);
+                app.write_cache();
+                app.list_state.select(Some(0));
+            }
+        }
+    }
+
+    //+/- adjust the selected column width
+    pub fn resize(self, app: &mut app::App, grow: bool) {
+        let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        let settings = BoardSettings::settings(&board_meta);
+        if let Some(BoardSetting::ColumnWidth(id)) = app.list_state.selected().and_then(|i| settings.get(i)).cloned() {
+            BoardSettings::update_board_meta(app, |meta, _| {
+                let width = meta.column_width(&id);
+                let width = if grow { width.saturating_add(1) } else { width.saturating_sub(1).max(3) };
+                meta.column_widths.insert(id, width);
+            });
+        }
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        app.key_input = Vec::new();
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => self.keyenter(app),
+            KeyCode::Char('+') => self.resize(app, true),
+            KeyCode::Char('-') => self.resize(app, false),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for BoardSettings {
+    fn title(&self) -> &'static str {
+        "Board Settings"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(
+            app,
+            &[("select", Some("Change / Clear")), ("wider", None), ("narrower", None), ("back", None)],
+        )
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        BoardSettings::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(BoardSettings::settings(&app.cache.get_board_meta(app.board_detail.id.clone())).len())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct NewBoard;
+
+impl NewBoard {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        //Whichever field isn't being typed into is shown in the title
+        let (label, other) = if app.editing_workspace {
+            ("Workspace Id: ", format!("\"{}\"", app.board_workspace.iter().collect::<String>()))
+        } else {
+            ("Board Name: ", Self::workspace_title(&app.board_workspace.iter().collect::<String>()))
+        };
+        let title = format!("Create {} Board {} (Tab: Change Kind, F2: Workspace)", app.board_kind, other);
+        let p = components::get_input_block(app, label, &title);
+        rect.render_widget(p, chunks[1]);
+    }
+
+    fn workspace_title(workspace: &str) -> String {
+        if workspace.trim().is_empty() {
+            String::from("in the main workspace")
+        } else {
+            format!("in workspace {}", workspace.trim())
+        }
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = Vec::new();
+        app.board_workspace = Vec::new();
+        app.editing_workspace = false;
+        app.active_menu_item = MenuItem::Boards;
+    }
+
+    //Swaps the typed text between the name and workspace fields
+    pub fn switch_field(self, app: &mut app::App) {
+        std::mem::swap(&mut app.key_input, &mut app.board_workspace);
+        app.editing_workspace = !app.editing_workspace;
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        let (name, workspace) = if app.editing_workspace {
+            (&app.board_workspace, &app.key_input)
+        } else {
+            (&app.key_input, &app.board_workspace)
+        };
+        let board_name: String = name.iter().collect();
+        let workspace: String = workspace.iter().collect();
+        if board_name.trim().is_empty() {
+            return;
+        }
+        //An empty workspace is the main one, anything else has to be an id
+        let workspace_id = match workspace.trim() {
+            "" => None,
+            w => match w.parse::<i64>() {
+                Ok(id) => Some(id),
+                Err(_) => return,
+            },
+        };
+        app.api.create_board(board_name.trim().to_string(), app.board_kind, workspace_id);
+        app.board_workspace = Vec::new();
+        app.editing_workspace = false;
+        refresh_boards(app);
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Tab => app.board_kind = app.board_kind.next(),
+            KeyCode::F(2) => self.switch_field(app),
+            KeyCode::Enter => self.keyenter(app),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for NewBoard {
+    fn title(&self) -> &'static str {
+        "Create Board"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        NewBoard::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DuplicateBoard;
+
+impl DuplicateBoard {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        let title = format!("Duplicate Board ({})", app.duplicate_type);
+        let p = components::get_input_block(app, "New Board Name: ", &title);
+        rect.render_widget(p, chunks[1]);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = Vec::new();
+        app.active_menu_item = MenuItem::BoardOptions;
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => {
+                let board_name: String = app.key_input.iter().collect();
+                app.api.duplicate_board(
+                    app.board_detail.id.clone(),
+                    app.duplicate_type,
+                    Some(board_name),
+                    None,
+                    false,
+                );
+                refresh_boards(app);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for DuplicateBoard {
+    fn title(&self) -> &'static str {
+        "Duplicate Board"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        DuplicateBoard::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct BoardSubscribers;
+
+impl BoardSubscribers {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        let items = app.users.iter().map(|x| ListItem::new(x.name.clone())).collect::<Vec<ListItem>>();
+
+        let list_len = items.len();
+        let option_list = List::new(items)
+            .block(Block::default().title("Add Subscriber").borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
+            .highlight_symbol(">>");
+
+        components::render_list(rect, option_list, chunks[1], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::BoardOptions;
+        app.key_input = vec![]; 
+        app.list_state.select(Some(0));
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => {
+                if let Some(user) = app.list_state.selected().and_then(|i| app.users.get(i)) {
+                    app.api.add_subscribers(app.board_detail.id.clone(), vec![user.id], false);
+                }
+                refresh_boards(app);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for BoardSubscribers {
+    fn title(&self) -> &'static str {
+        "Board Subscribers"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        BoardSubscribers::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(app.users.len())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GroupList;
+
+impl GroupList {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        //Default chunks, search, and menu
+        let chunks = components::get_help_chunks(&rect);
+        components::render_menu(rect, chunks[0], app);
+        let search_block = components::get_search_block(&app);
+        let help_block = components::get_help_block(&app); 
+
+        //Filter items
+        let filtered = utils::filter_groups(&app);
+        let board_block = Block::default()
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::White))
+            .title("Group")
+            .border_type(BorderType::Plain);
+
+        //Create item list, with a swatch of the group color
+        let query = app.key_input.iter().collect::<String>();
+        let list_items: Vec<ListItem> = filtered
+            .iter()
+            .map(|x| {
+                let mut spans = vec![Span::styled("■ ", Style::default().fg(utils::hex_to_color(&x.color)))];
+                spans.extend(components::highlight_matches(&x.title, &query));
+                ListItem::new(Spans::from(spans))
+            })
+            .collect();
+
+        let list_len = list_items.len();
+        let item_list = List::new(list_items).block(board_block).highlight_style(
+            Style::default()
+                .bg(Color::Yellow)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        //Render components
+        rect.render_widget(help_block, chunks[1]); 
+        rect.render_widget(search_block, chunks[3]);
+        components::render_list(rect, item_list, chunks[2], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    pub fn keyright(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Items;
+        app.key_input = vec![]; 
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Boards;
+        app.key_input = vec![]; 
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        let filtered = utils::filter_groups(&app);
+        if let Some(group) = app.list_state.selected().and_then(|i| filtered.get(i)) {
+            open_group(app, group.clone());
+        }
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Right => self.keyright(app),
+            KeyCode::Enter => self.keyenter(app),
+            KeyCode::F(3) => { //Create Group in Current Board
+                app.key_input = Vec::new();
+                app.active_menu_item = MenuItem::NewGroup;
+            }
+            KeyCode::F(4) => { //Options for Selected Group
+                let filtered = utils::filter_groups(app);
+                if let Some(group) = app.list_state.selected().and_then(|i| filtered.get(i)) {
+                    app.group_detail = group.clone();
+                    app.key_input = Vec::new();
+                    app.list_state.select(Some(0));
+                    app.active_menu_item = MenuItem::GroupOptions;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for GroupList {
+    fn title(&self) -> &'static str {
+        "Groups"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(app, &[("create_group", None), ("group_options", None)])
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        GroupList::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(utils::filter_groups(app).len())
+    }
+}
+
+//Reload groups after a group mutation and return to the group list
+fn refresh_groups(app: &mut app::App) {
+    app.groups = app.api.group_list(app.board_detail.id.clone());
+    app.key_input = Vec::new();
+    app.list_state.select(Some(0));
+    app.active_menu_item = MenuItem::Groups;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GroupOptions;
+
+impl GroupOptions {
+    const OPTIONS: [&'static str; 6] = [
+        "Rename Group",
+        "Duplicate Group",
+        "Archive Group",
+        "Delete Group",
+        "Move Up",
+        "Move Down",
+    ];
+
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        //Default chunks, search, and menu
+        let chunks = components::get_default_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+
+        let items = Self::OPTIONS
+            .iter()
+            .enumerate()
+            .map(|(i, x)| components::option_item(app, i, x))
+            .collect::<Vec<ListItem>>();
+
+        let list_len = items.len();
+        let option_list = List::new(items)
+            .block(Block::default().title(app.group_detail.title.clone()).borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
+            .highlight_symbol(">>");
+
+        components::render_list(rect, option_list, chunks[1], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    pub fn keyright(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Home;
+        app.key_input = vec![]; 
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Groups;
+        app.key_input = vec![]; 
+        app.list_state.select(Some(0));
+    }
+
+    //Neighbouring group in board order, used for reordering
+    fn neighbour(app: &app::App, up: bool) -> Option<objects::Group> {
+        let index = app.groups.iter().position(|g| g.id == app.group_detail.id)?;
+        if up {
+            index.checked_sub(1).and_then(|i| app.groups.get(i)).cloned()
+        } else {
+            app.groups.get(index + 1).cloned()
+        }
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        let board_id = app.board_detail.id.clone();
+        let group_id = app.group_detail.id.clone();
+        match app.list_state.selected().unwrap_or(0) {
+            0 => {
+                app.key_input = app.group_detail.title.chars().collect();
+                app.active_menu_item = MenuItem::RenameGroup;
+            }
+            1 => {
+                app.key_input = app.group_detail.title.chars().collect();
+                app.active_menu_item = MenuItem::DuplicateGroup;
+            }
+            2 if app.confirm() => {
+                app.api.archive_group(board_id, group_id);
+                refresh_groups(app);
+            }
+            3 if app.confirm() => {
+                app.api.delete_group(board_id, group_id);
+                refresh_groups(app);
+            }
+            4 | 5 => {
+                let up = app.list_state.selected() == Some(4);
+                if let Some(neighbour) = Self::neighbour(app, up) {
+                    app.api.move_group(board_id, group_id, neighbour.id, up);
+                }
+                refresh_groups(app);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Right => self.keyright(app),
+            KeyCode::Enter => self.keyenter(app),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for GroupOptions {
+    fn title(&self) -> &'static str {
+        "Group Options"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        GroupOptions::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, _app: &app::App) -> Option<usize> {
+        Some(GroupOptions::OPTIONS.len())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct NewGroup;
+
+impl NewGroup {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        let p = components::get_input_block(app, "Group Name: ", "Create Group");
+        rect.render_widget(p, chunks[1]);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = Vec::new();
+        app.active_menu_item = MenuItem::Groups;
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => {
+                let group_name: String = app.key_input.iter().collect();
+                if group_name.trim().is_empty() {
+                    return;
+                }
+                app.api.create_group(app.board_detail.id.clone(), group_name.trim().to_string());
+                refresh_groups(app);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for NewGroup {
+    fn title(&self) -> &'static str {
+        "Create Group"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        NewGroup::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct RenameGroup;
+
+impl RenameGroup {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        let p = components::get_input_block(app, "Group Name: ", "Rename Group");
+        rect.render_widget(p, chunks[1]);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = Vec::new();
+        app.active_menu_item = MenuItem::GroupOptions;
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => {
+                let title: String = app.key_input.iter().collect();
+                if title.trim().is_empty() {
+                    return;
+                }
+                app.api.rename_group(app.board_detail.id.clone(), app.group_detail.id.clone(), title.trim().to_string());
+                refresh_groups(app);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for RenameGroup {
+    fn title(&self) -> &'static str {
+        "Rename Group"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        RenameGroup::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DuplicateGroup;
+
+impl DuplicateGroup {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        let p = components::get_input_block(app, "New Group Name: ", "Duplicate Group");
+        rect.render_widget(p, chunks[1]);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = Vec::new();
+        app.active_menu_item = MenuItem::GroupOptions;
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => {
+                let title: String = app.key_input.iter().collect();
+                if title.trim().is_empty() {
+                    return;
+                }
+                app.api.duplicate_group(app.board_detail.id.clone(), app.group_detail.id.clone(), title.trim().to_string());
+                refresh_groups(app);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for DuplicateGroup {
+    fn title(&self) -> &'static str {
+        "Duplicate Group"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        DuplicateGroup::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GroupListForMove;
+
+impl GroupListForMove {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        GroupList::render(rect, app); 
+    }
+
+    pub fn keyright(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Home;
+        app.key_input = vec![]; 
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::ItemDetail; 
+        app.key_input = vec![]; 
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        let filtered = utils::filter_groups(&app);
+        let selected_group = match app.list_state.selected().and_then(|i| filtered.get(i)) {
+            Some(group) => group.clone(),
+            None => return,
+        };
+        app.active_menu_item = MenuItem::ItemDetail;
+        if app.bulk {
+            start_bulk(app, bulk::BulkAction::MoveToGroup(selected_group.id));
+            return;
+        }
+        app.api.move_item(app.item_detail.id.clone(), selected_group.id.clone()); 
+        app.fetch_item_detail(app.item_detail.id.clone()); 
+        app.group_detail = selected_group.clone(); 
+        app.fetch_items(app.board_detail.id.clone(), selected_group.id);
+        app.key_input = Vec::new();
+        app.list_state.select(Some(0));
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Right => self.keyright(app),
+            KeyCode::Enter => self.keyenter(app),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for GroupListForMove {
+    fn title(&self) -> &'static str {
+        "Move Item to Group"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        GroupListForMove::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(utils::filter_groups(app).len())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ItemList;
+
+impl ItemList {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        //Default chunks, search, and menu. The help bar grows to fit the filter chips.
+        let help_height = if utils::item_filter(app).is_empty() { 3 } else { 4 };
+        let chunks = components::get_help_chunks_with_height(rect, help_height);
+        components::render_menu(rect, chunks[0], app);
+        let search_block = components::get_search_block(&app);
+        let help_block = components::get_help_block(&app); 
+
+        //Filter items
+        let filtered = utils::filter_items(app);
+        let title = if app.selected_items.is_empty() {
+            String::from("Item")
+        } else {
+            format!("Item ({} selected)", app.selected_items.len())
+        };
+        let board_block = Block::default()
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::White))
+            .title(title)
+            .border_type(BorderType::Plain);
+
+        //Create item list, marking multi-selected items
+        let terms = utils::search_terms(&app.key_input.iter().collect::<String>());
+        let list_items: Vec<ListItem> = filtered
+            .iter()
+            .map(|x| {
+                let mut spans = if app.selected_items.contains(&x.id) {
+                    vec![Span::raw("[x] ")]
+                } else if !app.selected_items.is_empty() {
+                    vec![Span::raw("[ ] ")]
+                } else {
+                    Vec::new()
+                };
+                spans.extend(components::highlight_matches(&x.name, &terms));
+                ListItem::new(Spans::from(spans))
+            })
+            .collect();
+
+        let list_len = list_items.len();
+        let item_list = List::new(list_items).block(board_block).highlight_style(
+            Style::default()
+                .bg(Color::Yellow)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        //Render components
+        rect.render_widget(help_block, chunks[1]); 
+        rect.render_widget(search_block, chunks[3]);
+        components::render_list(rect, item_list, chunks[2], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    pub fn keyright(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::ItemDetail;
+        app.key_input = vec![]; 
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Groups;
+        app.key_input = vec![]; 
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        let item_filtered = utils::filter_items(app);
+        let selected_item = match app.list_state.selected().and_then(|i| item_filtered.get(i)) {
+            Some(item) => item.clone(),
+            None => return,
+        };
+        app.active_menu_item = MenuItem::ItemDetail;
+        app.fetch_item_detail(selected_item.id);
+        remember(app, cache::RecentKind::Item);
+        app.key_input = Vec::new();
+        app.list_state.select(Some(0));
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Right => self.keyright(app),
+            KeyCode::Enter => self.keyenter(app),
+            KeyCode::F(1) => app.filter = filter::FilterState::default(), //Clear Filters
+            KeyCode::F(2) => app.filter.assigned_to_me = !app.filter.assigned_to_me,
+            KeyCode::F(4) => {
+                app.status_labels = app.api.board_columns(app.board_detail.id.clone());
+                app.view_query = app.key_input.clone();
+                app.key_input = Vec::new();
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::FilterOptions;
+            }
+            KeyCode::F(3) => { //Create Item in Current Group
+                app.active_menu_item = MenuItem::NewItem; 
+            }
+            //Space selects in normal mode or while the search box is empty, otherwise it's part of the query
+            KeyCode::Char(' ') if self.selects(app) => self.toggle_selected(app),
+            KeyCode::F(5) => self.select_all(app),
+            KeyCode::F(6) if !app.selected_items.is_empty() => {
+                app.key_input = Vec::new();
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::BulkOptions;
+            }
+            KeyCode::F(8) => {
+                app.key_input = Vec::new();
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::SortOptions;
+            }
+            KeyCode::F(9) => {
+                let mut board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+                if let Some(item_sort) = board_meta.sort.as_mut() {
+                    item_sort.descending = !item_sort.descending;
+                    board_meta.id = app.board_detail.id.clone();
+                    app.cache.update_board_meta(board_meta);
+                    app.write_cache();
+                }
+            }
+            //Keeps the list to the open group, or adds it to the groups already filtered on
+            KeyCode::F(11) => {
+                let group_id = app.group_detail.id.clone();
+                app.filter.toggle_group(&group_id);
+                app.load_board_items();
+            }
+            KeyCode::F(10) => {
+                app.view_query = app.key_input.clone();
+                app.key_input = Vec::new();
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::ViewOptions;
+            }
+            KeyCode::F(7) => { //Table of the Listed Items
+                app.table_items = utils::filter_items(app);
+                app.table_board_scope = false;
+                app.table_column_offset = 0;
+                app.table_query = std::mem::take(&mut app.key_input);
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::ItemTable;
+            }
+            _ => {}
+        }
+    }
+
+    //Space selects in normal mode or while the search box is empty
+    pub fn selects(self, app: &app::App) -> bool {
+        !app.is_typing() || app.key_input.is_empty()
+    }
+
+    pub fn toggle_selected(self, app: &mut app::App) {
+        let filtered = utils::filter_items(app);
+        if let Some(item) = app.list_state.selected().and_then(|i| filtered.get(i)) {
+            if let Some(pos) = app.selected_items.iter().position(|id| *id == item.id) {
+                app.selected_items.remove(pos);
+            } else {
+                app.selected_items.push(item.id.clone());
+            }
+        }
+    }
+
+    //Selects every item matching the current filter, or clears them if already selected
+    pub fn select_all(self, app: &mut app::App) {
+        let filtered = utils::filter_items(app);
+        if filtered.iter().all(|item| app.selected_items.contains(&item.id)) {
+            app.selected_items.retain(|id| !filtered.iter().any(|item| item.id == *id));
+        } else {
+            for item in filtered {
+                if !app.selected_items.contains(&item.id) {
+                    app.selected_items.push(item.id);
+                }
+            }
+        }
+    }
+}
+
+impl<B: Backend> View<B> for ItemList {
+    fn title(&self) -> &'static str {
+        "Items"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(
+            app,
+            &[
+                ("clear_filters", None),
+                ("assigned_to_me", None),
+                ("create_item", None),
+                ("filters", None),
+                ("toggle_select", None),
+                ("select_all", None),
+                ("bulk_actions", None),
+                ("table_view", None),
+                ("sort", None),
+                ("reverse_sort", None),
+                ("views", None),
+                ("group_filter", None),
+            ],
+        )
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        ItemList::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn takes_key(&self, event: &KeyEvent, app: &app::App) -> bool {
+        event.code == KeyCode::Char(' ') && self.selects(app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(utils::filter_items(app).len())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ItemTable;
+
+impl ItemTable {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_help_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+
+        //Rows start under the header, scrolled from the top every frame as the state is new
+        let inner = Block::default().borders(Borders::ALL).inner(chunks[2]);
+        let row_area = Rect::new(inner.x, inner.y + 1, inner.width, inner.height.saturating_sub(1));
+        let selected = app.list_state.selected().unwrap_or(0);
+        app.mouse.list = Some(mouse::ListTarget {
+            area: row_area,
+            offset: mouse::list_offset(0, selected, app.table_items.len(), row_area.height as usize),
+            len: app.table_items.len(),
+        });
+
+        let help_block = components::get_help_block(app);
+
+        let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        let columns = utils::table_columns(&board_meta, &app.table_items);
+
+        //Name stays frozen on the left, the rest scroll horizontally from the offset
+        let name_width: u16 = 30;
+        let mut remaining = chunks[2].width.saturating_sub(name_width + 2);
+        let mut shown: Vec<&(String, String)> = Vec::new();
+        for column in columns.iter().skip(app.table_column_offset) {
+            let width = board_meta.column_width(&column.0) + 1;
+            if width > remaining {
+                break;
+            }
+            remaining -= width;
+            shown.push(column);
+        }
+
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let mut header = vec![Cell::from(Span::styled("Name", bold))];
+        header.extend(shown.iter().map(|(_, title)| Cell::from(Span::styled(title.clone(), bold))));
+        let mut widths = vec![Constraint::Length(name_width)];
+        widths.extend(shown.iter().map(|(id, _)| Constraint::Length(board_meta.column_width(id))));
+
+        let rows = app
+            .table_items
+            .iter()
+            .map(|item| {
+                let mut cells = vec![Cell::from(item.name.clone())];
+                cells.extend(shown.iter().map(|(id, _)| {
+                    let text = item
+                        .column_values
+                        .iter()
+                        .find(|cv| cv.id == *id)
+                        .map(|cv| cv.text.clone())
+                        .unwrap_or_default();
+                    Cell::from(text)
+                }));
+                Row::new(cells)
+            })
+            .collect::<Vec<Row>>();
+
+        let scope = if app.table_board_scope {
+            app.board_detail.name.clone()
+        } else {
+            app.group_detail.title.clone()
+        };
+        let title = format!(
+            "{} (columns {}-{} of {})",
+            scope,
+            (app.table_column_offset + 1).min(columns.len()),
+            app.table_column_offset + shown.len(),
+            columns.len()
+        );
+        let table = Table::new(rows)
+            .header(Row::new(header))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::White))
+                    .title(title)
+                    .border_type(BorderType::Plain),
+            )
+            .widths(&widths)
+            .highlight_style(
+                Style::default()
+                    .bg(Color::Yellow)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        let mut table_state = TableState::default();
+        table_state.select(app.list_state.selected());
+
+        rect.render_widget(help_block, chunks[1]);
+        rect.render_stateful_widget(table, chunks[2], &mut table_state);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Items;
+        app.key_input = app.table_query.clone();
+        app.list_state.select(Some(0));
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        if let Some(item) = app.list_state.selected().and_then(|i| app.table_items.get(i)) {
+            app.fetch_item_detail(item.id.clone());
+            app.key_input = Vec::new();
+            app.list_state.select(Some(0));
+            app.active_menu_item = MenuItem::ItemDetail;
+        }
+    }
+
+    //Switch between the current group and the whole board
+    pub fn toggle_scope(self, app: &mut app::App) {
+        app.table_board_scope = !app.table_board_scope;
+        app.table_items = if app.table_board_scope {
+            let mut board_items = app.api.board_items(app.board_detail.id.clone());
+            app.index.replace_items(&board_items, &app.board_detail, None);
+            utils::sort_for_board(app, &mut board_items);
+            board_items
+        } else {
+            //Back to what the item list showed
+            utils::filter_items_matching(app, &app.table_query)
+        };
+        app.table_column_offset = 0;
+        app.list_state.select(Some(0));
+    }
+
+    fn column_count(app: &app::App) -> usize {
+        let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        utils::table_columns(&board_meta, &app.table_items).len()
+    }
+
+    pub fn scroll(self, app: &mut app::App, forward: bool) {
+        let count = Self::column_count(app);
+        if forward && app.table_column_offset + 1 < count {
+            app.table_column_offset += 1;
+        } else if !forward && app.table_column_offset > 0 {
+            app.table_column_offset -= 1;
+        }
+    }
+
+    //Updates the table settings of the current board and saves them
+    fn update_board_meta<F: FnOnce(&mut cache::BoardMeta, &[(String, String)])>(app: &mut app::App, f: F) {
+        let mut board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        board_meta.id = app.board_detail.id.clone();
+        let columns = utils::table_columns(&board_meta, &app.table_items);
+        f(&mut board_meta, &columns);
+        app.cache.update_board_meta(board_meta);
+        app.write_cache();
+    }
+
+    //Widens or narrows the first scrolled column
+    pub fn resize(self, app: &mut app::App, delta: i32) {
+        let offset = app.table_column_offset;
+        Self::update_board_meta(app, |board_meta, columns| {
+            if let Some((id, _)) = columns.get(offset) {
+                let width = (board_meta.column_width(id) as i32 + delta).max(3) as u16;
+                board_meta.column_widths.insert(id.clone(), width);
+            }
+        });
+    }
+
+    pub fn hide_column(self, app: &mut app::App) {
+        let offset = app.table_column_offset;
+        Self::update_board_meta(app, |board_meta, columns| {
+            if columns.len() > 1 {
+                board_meta.visible_columns = columns
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != offset)
+                    .map(|(_, (id, _))| id.clone())
+                    .collect();
+            }
+        });
+        app.table_column_offset = offset.min(Self::column_count(app).saturating_sub(1));
+    }
+
+    pub fn reset_columns(self, app: &mut app::App) {
+        Self::update_board_meta(app, |board_meta, _| {
+            board_meta.visible_columns = Vec::new();
+            board_meta.column_widths.clear();
+        });
+        app.table_column_offset = 0;
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        //No text input in the table
+        app.key_input = Vec::new();
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => self.keyenter(app),
+            KeyCode::Tab => self.scroll(app, true),
+            KeyCode::BackTab => self.scroll(app, false),
+            KeyCode::Char('+') => self.resize(app, 2),
+            KeyCode::Char('-') => self.resize(app, -2),
+            KeyCode::F(2) => self.toggle_scope(app),
+            KeyCode::F(4) => self.hide_column(app),
+            KeyCode::F(5) => self.reset_columns(app),
+            KeyCode::F(6) => {
+                app.board_columns = app.api.column_schema(app.board_detail.id.clone());
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::TableColumns;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for ItemTable {
+    fn title(&self) -> &'static str {
+        "Item Table"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(
+            app,
+            &[
+                ("scope", None),
+                ("scroll", None),
+                ("wider", None),
+                ("narrower", None),
+                ("hide_column", None),
+                ("reset_columns", None),
+                ("pick_columns", None),
+            ],
+        )
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        ItemTable::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(app.table_items.len())
+    }
+}
+
+//Every column on the board, to show or hide in the item table
+#[derive(Debug, Copy, Clone)]
+pub struct TableColumns;
+
+impl TableColumns {
+    //(id, title, type) of the board's columns; the name column is always shown
+    fn columns(app: &app::App) -> Vec<(String, String, String)> {
+        app.board_columns
+            .iter()
+            .filter(|c| c.type_ != "name")
+            .map(|c| (c.id.clone(), c.title.clone(), c.type_.clone()))
+            .collect()
+    }
+
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_help_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+        let help_block = components::get_help_block(app);
+        let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        let columns = TableColumns::columns(app);
+        let shown = utils::pick_table_columns(&board_meta, &columns);
+
+        let items = columns
+            .iter()
+            .map(|(id, title, type_)| {
+                let mark = if shown.iter().any(|(c, _)| c == id) { "[x]" } else { "[ ]" };
+                ListItem::new(Spans::from(vec![
+                    Span::raw(format!("{} {} ", mark, title)),
+                    Span::styled(format!("({})", type_), Style::default().fg(Color::DarkGray)),
+                ]))
+            })
+            .collect::<Vec<ListItem>>();
+
+        let list_len = items.len();
+        let column_list = List::new(items)
+            .block(Block::default().title("Table Columns").borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
+            .highlight_symbol(">>");
+
+        rect.render_widget(help_block, chunks[1]);
+        components::render_list(rect, column_list, chunks[2], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = Vec::new();
+        app.list_state.select(Some(0));
+        app.active_menu_item = MenuItem::ItemTable;
+    }
+
+    //Shows a hidden column at the end of the table or hides a shown one, keeping at least one
+    pub fn keyenter(self, app: &mut app::App) {
+        let columns = TableColumns::columns(app);
+        let id = match app.list_state.selected().and_then(|i| columns.get(i)) {
+            Some((id, _, _)) => id.clone(),
+            None => return,
+        };
+        let mut board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        board_meta.id = app.board_detail.id.clone();
+        let mut shown = utils::pick_table_columns(&board_meta, &columns)
+            .into_iter()
+            .map(|(c, _)| c)
+            .collect::<Vec<String>>();
+        match shown.iter().position(|c| *c == id) {
+            Some(_) if shown.len() == 1 => return,
+            Some(pos) => {
+                shown.remove(pos);
+            }
+            None => shown.push(id),
+        }
+        board_meta.visible_columns = shown;
+        app.cache.update_board_meta(board_meta);
+        app.write_cache();
+        app.table_column_offset = 0;
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => self.keyenter(app),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for TableColumns {
+    fn title(&self) -> &'static str {
+        "Table Columns"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(app, &[("select", Some("Show/Hide"))])
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        TableColumns::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(TableColumns::columns(app).len())
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SortOptions;
+
+impl SortOptions {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+        let current = app.cache.get_board_meta(app.board_detail.id.clone()).sort;
+
+        //Mark the active sort with its direction
+        let items = sort::sort_options(&app.items)
+            .into_iter()
+            .map(|(key, title)| match &current {
+                Some(item_sort) if item_sort.key == key => {
+                    let arrow = if item_sort.descending { "▼" } else { "▲" };
+                    ListItem::new(format!("{} {}", title, arrow))
+                }
+                _ => ListItem::new(title),
+            })
+            .collect::<Vec<ListItem>>();
+
+        let list_len = items.len();
+        let option_list = List::new(items)
+            .block(Block::default().title("Sort Items By").borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
+            .highlight_symbol(">>");
+
+        components::render_list(rect, option_list, chunks[1], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Items;
+        app.key_input = vec![];
+        app.list_state.select(Some(0));
+    }
+
+    //Choosing the active key again flips the direction
+    pub fn keyenter(self, app: &mut app::App) {
+        let options = sort::sort_options(&app.items);
+        if let Some((key, _)) = app.list_state.selected().and_then(|i| options.get(i)) {
+            let mut board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+            board_meta.id = app.board_detail.id.clone();
+            board_meta.sort = match board_meta.sort {
+                Some(item_sort) if item_sort.key == *key => Some(sort::ItemSort {
+                    key: key.clone(),
+                    descending: !item_sort.descending,
+                }),
+                _ => Some(sort::ItemSort {
+                    key: key.clone(),
+                    descending: false,
+                }),
+            };
+            app.cache.update_board_meta(board_meta);
+            app.write_cache();
+        }
+        self.keyleft(app);
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => self.keyenter(app),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for SortOptions {
+    fn title(&self) -> &'static str {
+        "Sort"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        SortOptions::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(sort::sort_options(&app.items).len())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FilterOption {
+    AssignedToMe,
+    CreatorIsMe,
+    DueWindow,
+    Status(String),
+    //Group id and title
+    Group(String, String),
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct FilterOptions;
+
+impl FilterOptions {
+    fn options(app: &app::App) -> Vec<FilterOption> {
+        let mut options = vec![
+            FilterOption::AssignedToMe,
+            FilterOption::CreatorIsMe,
+            FilterOption::DueWindow,
+        ];
+        let mut statuses: Vec<String> = Vec::new();
+        for label in app.status_labels.iter() {
+            let name = label.name.replace("\"", "");
+            if !name.is_empty() && !statuses.contains(&name) {
+                statuses.push(name);
+            }
+        }
+        options.extend(statuses.into_iter().map(FilterOption::Status));
+        options.extend(
+            app.groups
+                .iter()
+                .map(|g| FilterOption::Group(g.id.clone(), g.title.clone())),
+        );
+        options
+    }
+
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_help_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+        let help_block = components::get_help_block(app);
+
+        let mark = |on: bool| if on { "[x]" } else { "[ ]" };
+        let items = FilterOptions::options(app)
+            .into_iter()
+            .map(|option| {
+                let text = match option {
+                    FilterOption::AssignedToMe => format!("{} Assigned to me", mark(app.filter.assigned_to_me)),
+                    FilterOption::CreatorIsMe => format!("{} Created by me", mark(app.filter.creator_is_me)),
+                    FilterOption::DueWindow => match app.filter.due_within {
+                        Some(days) => format!("[x] Due: {}", filter::due_window_label(days)),
+                        None => String::from("[ ] Due: any time"),
+                    },
+                    FilterOption::Status(label) => {
+                        format!("{} Status: {}", mark(app.filter.statuses.contains(&label)), label)
+                    }
+                    FilterOption::Group(id, title) => {
+                        format!("{} Group: {}", mark(app.filter.groups.contains(&id)), title)
+                    }
+                };
+                ListItem::new(text)
+            })
+            .collect::<Vec<ListItem>>();
+
+        let list_len = items.len();
+        let option_list = List::new(items)
+            .block(Block::default().title("Filter Items").borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
+            .highlight_symbol(">>");
+
+        rect.render_widget(help_block, chunks[1]);
+        components::render_list(rect, option_list, chunks[2], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Items;
+        app.key_input = app.view_query.clone();
+        app.list_state.select(Some(0));
+    }
+
+    //Toggles the selected filter and stays on the list so several can be combined
+    pub fn keyenter(self, app: &mut app::App) {
+        let options = FilterOptions::options(app);
+        match app.list_state.selected().and_then(|i| options.get(i)) {
+            Some(FilterOption::AssignedToMe) => app.filter.assigned_to_me = !app.filter.assigned_to_me,
+            Some(FilterOption::CreatorIsMe) => app.filter.creator_is_me = !app.filter.creator_is_me,
+            Some(FilterOption::DueWindow) => app.filter.next_due_window(),
+            Some(FilterOption::Status(label)) => app.filter.toggle_status(label),
+            Some(FilterOption::Group(id, _)) => {
+                app.filter.toggle_group(id);
+                app.load_board_items();
+            }
+            None => {}
+        }
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        app.key_input = Vec::new();
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => self.keyenter(app),
+            KeyCode::F(1) => app.filter = filter::FilterState::default(),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for FilterOptions {
+    fn title(&self) -> &'static str {
+        "Filters"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(app, &[("select", Some("Toggle Filter")), ("clear_filters", None)])
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        FilterOptions::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(FilterOptions::options(app).len())
+    }
+}
+
+//Restores a saved view's filter, sort and columns
+fn apply_view(app: &mut app::App, view: cache::SavedView) {
+    let mut board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+    board_meta.id = app.board_detail.id.clone();
+    board_meta.sort = view.sort;
+    board_meta.visible_columns = view.visible_columns;
+    app.cache.update_board_meta(board_meta);
+    app.write_cache();
+    app.key_input = view.filter.chars().collect();
+    app.filter = view.chips;
+    app.load_board_items();
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ViewOptions;
+
+impl ViewOptions {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_help_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+        let help_block = components::get_help_block(app);
+        let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+
+        let mut items = vec![ListItem::new(Span::styled(
+            "Save Current View...",
+            Style::default().fg(Color::LightBlue),
+        ))];
+        items.extend(board_meta.views.iter().map(|view| {
+            let mut spans = vec![Span::raw(view.name.clone())];
+            if board_meta.default_view.as_ref() == Some(&view.name) {
+                spans.push(Span::styled(" (default)", Style::default().fg(Color::Yellow)));
+            }
+            let mut summary = view.chips.chips(&app.groups);
+            if !view.filter.is_empty() {
+                summary.insert(0, view.filter.clone());
+            }
+            if !summary.is_empty() {
+                spans.push(Span::styled(
+                    format!("  {}", summary.join(" | ")),
+                    Style::default().add_modifier(Modifier::ITALIC).fg(Color::Gray),
+                ));
+            }
+            ListItem::new(Spans::from(spans))
+        }));
+
+        let list_len = items.len();
+        let option_list = List::new(items)
+            .block(Block::default().title("Views").borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
+            .highlight_symbol(">>");
+
+        rect.render_widget(help_block, chunks[1]);
+        components::render_list(rect, option_list, chunks[2], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    fn count(app: &app::App) -> usize {
+        app.cache.get_board_meta(app.board_detail.id.clone()).views.len() + 1
+    }
+
+    //The saved view under the cursor, skipping the "save" entry
+    fn selected_view(app: &app::App) -> Option<cache::SavedView> {
+        let index = app.list_state.selected()?.checked_sub(1)?;
+        app.cache.get_board_meta(app.board_detail.id.clone()).views.get(index).cloned()
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = app.view_query.clone();
+        app.active_menu_item = MenuItem::Items;
+        app.list_state.select(Some(0));
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        match Self::selected_view(app) {
+            Some(view) => {
+                apply_view(app, view);
+                app.active_menu_item = MenuItem::Items;
+                app.list_state.select(Some(0));
+            }
+            None => {
+                app.key_input = Vec::new();
+                app.active_menu_item = MenuItem::SaveView;
+            }
+        }
+    }
+
+    fn update_board_meta<F: FnOnce(&mut cache::BoardMeta)>(app: &mut app::App, f: F) {
+        let mut board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        board_meta.id = app.board_detail.id.clone();
+        f(&mut board_meta);
+        app.cache.update_board_meta(board_meta);
+        app.write_cache();
+    }
+
+    pub fn toggle_default(self, app: &mut app::App) {
+        if let Some(view) = Self::selected_view(app) {
+            Self::update_board_meta(app, |board_meta| {
+                if board_meta.default_view.as_ref() == Some(&view.name) {
+                    board_meta.default_view = None;
+                } else {
+                    board_meta.default_view = Some(view.name);
+                }
+            });
+        }
+    }
+
+    pub fn remove(self, app: &mut app::App) {
+        if let Some(view) = Self::selected_view(app) {
+            Self::update_board_meta(app, |board_meta| {
+                board_meta.views.retain(|v| v.name != view.name);
+                if board_meta.default_view.as_ref() == Some(&view.name) {
+                    board_meta.default_view = None;
+                }
+            });
+            router::move_selection(app, Self::count(app), false);
+        }
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        //No text input in the picker
+        app.key_input = Vec::new();
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => self.keyenter(app),
+            KeyCode::F(2) => self.toggle_default(app),
+            KeyCode::Delete => self.remove(app),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for ViewOptions {
+    fn title(&self) -> &'static str {
+        "Saved Views"
+    }
+
+    fn help(&self, app: &app::App) -> Vec<(String, String)> {
+        help_bar(app, &[("select", Some("Apply")), ("toggle_default", None), ("remove_view", None)])
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        ViewOptions::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(ViewOptions::count(app))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SaveView;
+
+impl SaveView {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        let query: String = app.view_query.iter().collect();
+        let title = format!("Save View ({})", if query.is_empty() { "no filter" } else { query.as_str() });
+        let p = components::get_input_block(app, "View Name: ", &title);
+        rect.render_widget(p, chunks[1]);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.key_input = Vec::new();
+        app.list_state.select(Some(0));
+        app.active_menu_item = MenuItem::ViewOptions;
+    }
+
+    //Saving under an existing name replaces that view
+    pub fn keyenter(self, app: &mut app::App) {
+        let name: String = app.key_input.iter().collect();
+        if name.trim().is_empty() {
+            return;
+        }
+        let mut board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        board_meta.id = app.board_detail.id.clone();
+        let view = cache::SavedView {
+            name: name.trim().to_string(),
+            filter: app.view_query.iter().collect(),
+            sort: board_meta.sort.clone(),
+            visible_columns: board_meta.visible_columns.clone(),
+            chips: app.filter.clone(),
+        };
+        board_meta.views.retain(|v| v.name != view.name);
+        board_meta.views.push(view);
+        app.cache.update_board_meta(board_meta);
+        app.write_cache();
+        app.key_input = app.view_query.clone();
+        app.list_state.select(Some(0));
+        app.active_menu_item = MenuItem::Items;
+    }
+
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        match event.code {
+            KeyCode::Left => self.keyleft(app),
+            KeyCode::Enter => self.keyenter(app),
+            _ => {}
+        }
+    }
+}
+
+impl<B: Backend> View<B> for SaveView {
+    fn title(&self) -> &'static str {
+        "Save View"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        SaveView::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+}
+
+fn start_bulk(app: &mut app::App, action: bulk::BulkAction) {
+    app.bulk_job = Some(bulk::BulkJob::new(app, action));
+    app.bulk = false;
+    app.key_input = Vec::new();
+    app.active_menu_item = MenuItem::BulkProgress;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct BulkOptions;
+
+impl BulkOptions {
+    const OPTIONS: [&'static str; 5] = [
+        "Change Status",
+        "Move to Group",
+        "Assign User",
+        "Archive",
+        "Add Update",
+    ];
+
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+
+        let items = Self::OPTIONS.iter().map(|x| ListItem::new(*x)).collect::<Vec<ListItem>>();
+        let title = format!("Bulk Actions ({} items)", app.selected_items.len());
+
+        let list_len = items.len();
+        let option_list = List::new(items)
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
+            .highlight_symbol(">>");
+
+        components::render_list(rect, option_list, chunks[1], list_len, &mut app.list_state, &mut app.mouse);
+    }
+
+    pub fn keyleft(self, app: &mut app::App) {
+        app.active_menu_item = MenuItem::Items;
+        app.key_input = vec![]; 
+        app.list_state.select(Some(0));
+    }
+
+    pub fn keyenter(self, app: &mut app::App) {
+        //Status and user columns are picked from the first selected item
+        let first = app.selected_items.first().cloned().unwrap_or_default();
+        app.bulk = true;
+        app.key_input = Vec::new();
+        match app.list_state.selected().unwrap_or(0) {
+            0 => {
+                app.fetch_item_detail(first);
+                app.list_state.select(Some(0));
+                let board_cache = app.cache.get_board_meta(app.board_detail.id.clone());
+                if !board_cache.status_column_id.is_empty() {
+                    app.status_labels = app.api.board_columns(app.board_detail.id.clone());
+                    app.active_menu_item = MenuItem::StatusOptions;
+                } else {
+                    app.active_menu_item = MenuItem::StatusColumnOptions;
+                }
+            }
+            1 => {
+                app.list_state.select(Some(0));
+                app.active_menu_item = MenuItem::GroupsForMove;
+            }
+            2 => {
+                app.fetch_item_detail(first);
+                app.list_state.select(Some(0));
+                let board_cache = app.cache.get_board_meta(app.board_detail.id.clone());
+                if !board_cache.user_column_id.is_empty() {
+                    app.users = app.api.user_list(app.board_detail.id.clone());
+                    app.active_menu_item = MenuItem::UserOptions;
+                } else {
+                    app.active_menu_item = MenuItem::UserColumnOptions;
+                }
+            }
+            3 => start_bulk(app, bulk::BulkAction::Archive),
+            4 => app.active_menu_item = MenuItem::ItemUpdate,
+            _ => {}
+        }
     }
 
     pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
         match event.code {
-            KeyCode::Up => self.keyup(app),
-            KeyCode::Down => self.keydown(app),
             KeyCode::Left => self.keyleft(app),
-            KeyCode::Right => self.keyright(app),
             KeyCode::Enter => self.keyenter(app),
             _ => {}
         }
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-pub struct ItemList;
+impl<B: Backend> View<B> for BulkOptions {
+    fn title(&self) -> &'static str {
+        "Bulk Actions"
+    }
 
-impl ItemList {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &mut app::App) {
-        //Default chunks, search, and menu
-        let chunks = components::get_help_chunks(&rect);
-        let search_block = components::get_search_block(&app);
-        let menu_block = components::get_menu_block(&app);
-        let help_block = components::get_help_block(&app); 
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        BulkOptions::render(rect, app)
+    }
 
-        //Filter items
-        let filtered = utils::filter_items(app);
-        let board_block = Block::default()
-            .borders(Borders::ALL)
-            .style(Style::default().fg(Color::White))
-            .title("Item")
-            .border_type(BorderType::Plain);
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
 
-        //Create item list
-        let list_items: Vec<ListItem> = filtered
-            .iter()
-            .map(|x| ListItem::new(x.name.to_owned()))
-            .collect();
+    fn list_len(&self, _app: &app::App) -> Option<usize> {
+        Some(BulkOptions::OPTIONS.len())
+    }
+}
 
-        let item_list = List::new(list_items).block(board_block).highlight_style(
-            Style::default()
-                .bg(Color::Yellow)
-                .fg(Color::Black)
-                .add_modifier(Modifier::BOLD),
-        );
+#[derive(Debug, Copy, Clone)]
+pub struct BulkProgress;
+
+impl BulkProgress {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
+        let chunks = components::get_default_chunks(rect);
+        components::render_menu(rect, chunks[0], app);
+        let job = match &app.bulk_job {
+            Some(job) => job,
+            None => return,
+        };
+
+        let progress_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(2)].as_ref())
+            .split(chunks[1]);
 
-        //Render components
-        rect.render_widget(menu_block, chunks[0]);
-        rect.render_widget(help_block, chunks[1]); 
-        rect.render_stateful_widget(item_list, chunks[2], &mut app.list_state.clone());
-        rect.render_widget(search_block, chunks[3]);
+        let ratio = if job.total == 0 {
+            1.0
+        } else {
+            job.done() as f64 / job.total as f64
+        };
+        let gauge = Gauge::default()
+            .block(Block::default().title(job.action.title()).borders(Borders::ALL))
+            .gauge_style(Style::default().fg(Color::Yellow).bg(Color::Black))
+            .ratio(ratio)
+            .label(format!("{}/{}", job.done(), job.total));
+
+        //Per-item failure report
+        let failures = job
+            .failures
+            .iter()
+            .map(|failure| {
+                let name = app
+                    .items
+                    .iter()
+                    .find(|item| item.id == failure.item_id)
+                    .map(|item| item.name.clone())
+                    .unwrap_or_else(|| failure.item_id.clone());
+                ListItem::new(Spans::from(vec![
+                    Span::styled(name, Style::default().fg(Color::LightRed)),
+                    Span::raw(": "),
+                    Span::raw(failure.message.clone()),
+                ]))
+            })
+            .collect::<Vec<ListItem>>();
+        let report_title = if job.finished() {
+            format!("Done, {} failed (Enter to return)", job.failures.len())
+        } else {
+            format!("{} failed", job.failures.len())
+        };
+        let report = List::new(failures)
+            .block(Block::default().title(report_title).borders(Borders::ALL))
+            .style(Style::default().fg(Color::White));
+
+        rect.render_widget(gauge, progress_chunks[0]);
+        rect.render_widget(report, progress_chunks[1]);
+    }
+
+    //Runs one batch per tick so the progress gauge redraws in between
+    pub fn tick(&self, app: &mut app::App) {
+        let api = app.api.clone();
+        if let Some(job) = app.bulk_job.as_mut() {
+            if !job.finished() {
+                job.step(&*api);
+            }
+        }
     }
 
-    pub fn keyup(self, app: &mut app::App) {
-        if let Some(selected) = app.list_state.selected() {
-            let amount_boards = utils::filter_items(app).len();
-            if selected > 0 {
-                app.list_state.select(Some(selected - 1));
-            } else {
-                app.list_state.select(Some(amount_boards - 1));
+    pub fn keyenter(self, app: &mut app::App) {
+        if let Some(job) = &app.bulk_job {
+            if !job.finished() {
+                return;
             }
         }
+        app.bulk_job = None;
+        app.selected_items = Vec::new();
+        app.fetch_items(app.board_detail.id.clone(), app.group_detail.id.clone());
+        app.key_input = Vec::new();
+        app.list_state.select(Some(0));
+        app.active_menu_item = MenuItem::Items;
     }
 
-    pub fn keydown(self, app: &mut app::App) {
-        if let Some(selected) = app.list_state.selected() {
-            let list_length = utils::filter_items(app).len();
-            if selected >= list_length - 1 {
-                app.list_state.select(Some(0));
-            } else {
-                app.list_state.select(Some(selected + 1));
-            }
+    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
+        if let KeyCode::Enter = event.code {
+            self.keyenter(app)
         }
     }
+}
 
-    pub fn keyright(self, app: &mut app::App) {
-        app.active_menu_item = MenuItem::ItemDetail;
-        app.key_input = vec![]; 
+impl<B: Backend> View<B> for BulkProgress {
+    fn title(&self) -> &'static str {
+        "Bulk Progress"
     }
 
-    pub fn keyleft(self, app: &mut app::App) {
-        app.active_menu_item = MenuItem::Groups;
-        app.key_input = vec![]; 
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        BulkProgress::render(rect, app)
     }
 
-    pub fn keyenter(self, app: &mut app::App) {
-        let item_filtered = utils::filter_items(app);
-        app.active_menu_item = MenuItem::ItemDetail;
-        let selected_item = item_filtered
-            .get(app.list_state.selected().unwrap())
-            .unwrap()
-            .clone();
-        app.item_detail = queries::item_detail(&app.client, selected_item.id);
-        app.key_input = Vec::new();
-        app.list_state.select(Some(0));
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
     }
 
-    pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
-        match event.code {
-            KeyCode::Up => self.keyup(app),
-            KeyCode::Down => self.keydown(app),
-            KeyCode::Left => self.keyleft(app),
-            KeyCode::Right => self.keyright(app),
-            KeyCode::Enter => self.keyenter(app),
-            KeyCode::F(1) => app.f = KeyCode::F(1), //No Filter
-            KeyCode::F(2) => app.f = KeyCode::F(2), //Filter By Assigned
-            KeyCode::F(3) => { //Create Item in Current Group
-                app.active_menu_item = MenuItem::NewItem; 
-            }
-            _ => {}
-        }
+    fn tick(&self, app: &mut app::App) {
+        BulkProgress::tick(self, app)
     }
 }
 
@@ -500,10 +3237,61 @@ impl ItemList {
 pub struct ItemDetail;
 
 impl ItemDetail {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &app::App) {
+    //The item's details, one entry per line with each update and reply on its own line
+    pub fn lines(item: &objects::Item) -> Vec<Vec<(String, Style)>> {
+        let label = Style::default()
+            .add_modifier(Modifier::ITALIC)
+            .fg(Color::LightBlue);
+        let author = Style::default()
+            .add_modifier(Modifier::BOLD)
+            .fg(Color::LightCyan);
+        let subscribers = item
+            .subscribers
+            .iter()
+            .map(|sub| sub.name.clone())
+            .collect::<Vec<String>>()
+            .join(", ");
+        let mut lines = vec![
+            vec![(String::from("Name: "), label), (item.name.clone(), Style::default())],
+            vec![(String::from("Subscribers: "), label), (subscribers, Style::default())],
+            vec![(String::from("Updated at: "), label), (item.updated_at.clone(), Style::default())],
+            vec![(String::from("Group: "), label), (item.group.title.clone(), Style::default())],
+        ];
+
+        let mut column_values = vec![(String::from("Column Values: "), label)];
+        for cv in item.column_values.iter() {
+            if !cv.text.is_empty() {
+                column_values.append(&mut vec![
+                    (cv.title.clone(), author),
+                    (String::from(": "), Style::default()),
+                    (cv.text.clone(), Style::default().add_modifier(Modifier::ITALIC)),
+                    (String::from(" | "), Style::default()),
+                ])
+            }
+        }
+        lines.push(column_values);
+
+        //Update thread, newest first as the API returns it
+        lines.push(vec![(String::from("Updates: "), label)]);
+        for update in item.updates.iter() {
+            lines.push(vec![
+                (format!("  {}: ", update.creator.name), author),
+                (update.text_body.clone(), Style::default()),
+            ]);
+            for reply in update.replies.iter() {
+                lines.push(vec![
+                    (format!("    ↳ {}: ", reply.creator.name), author),
+                    (reply.text_body.clone(), Style::default()),
+                ]);
+            }
+        }
+        lines
+    }
+
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
         //Default chunks, search, and menu
         let chunks = components::get_default_chunks(&rect);
-        let menu_block = components::get_menu_block(&app);
+        components::render_menu(rect, chunks[0], app);
 
         //Board detail block
         let board_block = Block::default()
@@ -512,106 +3300,44 @@ impl ItemDetail {
             .title("Item")
             .border_type(BorderType::Plain);
 
-        //Span Vec
-        let mut column_value_span: Vec<Span> = vec![Span::styled(
-            "Column Values: ",
-            Style::default()
-                .add_modifier(Modifier::ITALIC)
-                .fg(Color::LightBlue),
-        )];
-        for cv in app.item_detail.column_values.iter() {
-            if cv.text != "" {
-                column_value_span.append(&mut vec![
-                    Span::styled(
-                        cv.title.clone(),
-                        Style::default()
-                            .add_modifier(Modifier::BOLD)
-                            .fg(Color::LightCyan),
-                    ),
-                    Span::raw(": "),
-                    Span::styled(
-                        cv.text.clone(),
-                        Style::default().add_modifier(Modifier::ITALIC),
-                    ),
-                    Span::raw(" | "),
-                ])
+        //Wrapped here rather than by the paragraph so links can be found on screen
+        let area = board_block.inner(chunks[1]);
+        let rows = components::wrap_lines(ItemDetail::lines(&app.item_detail), area.width as usize);
+        app.detail_scroll = app.detail_scroll.min(rows.len().saturating_sub(area.height as usize));
+        let mut text = Vec::new();
+        for (y, row) in rows.into_iter().skip(app.detail_scroll).take(area.height as usize).enumerate() {
+            let mut x = area.x;
+            let mut spans = Vec::new();
+            for (content, style, link) in row {
+                let width = content.chars().count() as u16;
+                if let Some(url) = link {
+                    app.mouse.links.push((Rect::new(x, area.y + y as u16, width, 1), url));
+                }
+                x += width;
+                spans.push(Span::styled(content, style));
             }
+            text.push(Spans::from(spans));
         }
-        let text = vec![
-            Spans::from(vec![
-                Span::styled(
-                    "Name: ",
-                    Style::default()
-                        .add_modifier(Modifier::ITALIC)
-                        .fg(Color::LightBlue),
-                ),
-                Span::raw(app.item_detail.name.clone()),
-            ]),
-            Spans::from(vec![
-                Span::styled(
-                    "Subscribers: ",
-                    Style::default()
-                        .add_modifier(Modifier::ITALIC)
-                        .fg(Color::LightBlue),
-                ),
-                Span::raw(
-                    app.item_detail
-                        .subscribers
-                        .iter()
-                        .map(|sub| sub.name.clone())
-                        .collect::<Vec<String>>()
-                        .join(", "),
-                ),
-            ]),
-            Spans::from(vec![
-                Span::styled(
-                    "Updated at: ",
-                    Style::default()
-                        .add_modifier(Modifier::ITALIC)
-                        .fg(Color::LightBlue),
-                ),
-                Span::raw(app.item_detail.updated_at.clone()),
-            ]),
-            Spans::from(vec![
-                Span::styled(
-                    "Group: ",
-                    Style::default()
-                        .add_modifier(Modifier::ITALIC)
-                        .fg(Color::LightBlue),
-                ),
-                Span::raw(app.item_detail.group.title.clone()),
-            ]),
-            Spans::from(vec![
-                Span::styled(
-                    "Updates: ",
-                    Style::default()
-                        .add_modifier(Modifier::ITALIC)
-                        .fg(Color::LightBlue),
-                ),
-                Span::raw(
-                    app.item_detail
-                        .updates
-                        .iter()
-                        .map(|update| update.text_body.clone())
-                        .collect::<Vec<String>>()
-                        .join(" | "),
-                ),
-            ]),
-            Spans::from(column_value_span),
-        ];
 
         //Paragraph
         let p = Paragraph::new(text)
             .block(board_block)
             .style(Style::default().fg(Color::White).bg(Color::Black))
-            .alignment(Alignment::Left)
-            .wrap(Wrap { trim: true });
+            .alignment(Alignment::Left);
 
         //Render Components
-        rect.render_widget(menu_block, chunks[0]);
         rect.render_widget(p, chunks[1]);
     }
 
+    //Up and Down scroll through long update threads
+    pub fn scroll(self, app: &mut app::App, down: bool) {
+        app.detail_scroll = if down {
+            app.detail_scroll + 1
+        } else {
+            app.detail_scroll.saturating_sub(1)
+        };
+    }
+
     pub fn keyright(self, app: &mut app::App) {
         app.active_menu_item = MenuItem::Home;
         app.key_input = vec![]; 
@@ -624,6 +3350,7 @@ impl ItemDetail {
 
     pub fn keyenter(self, app: &mut app::App) {
         app.active_menu_item = MenuItem::ItemOptions;
+        app.bulk = false;
         app.key_input = Vec::new();
         app.list_state.select(Some(0));
     }
@@ -633,28 +3360,45 @@ impl ItemDetail {
             KeyCode::Left => self.keyleft(app),
             KeyCode::Right => self.keyright(app),
             KeyCode::Enter => self.keyenter(app),
+            KeyCode::Up => self.scroll(app, false),
+            KeyCode::Down => self.scroll(app, true),
             _ => {}
         }
     }
 }
 
+impl<B: Backend> View<B> for ItemDetail {
+    fn title(&self) -> &'static str {
+        "Item Detail"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        ItemDetail::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct ItemOptions;
 
 impl ItemOptions {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &mut app::App) {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
         //Default chunks, search, and menu
         let chunks = components::get_default_chunks(&rect);
 
         let items = [ListItem::new("Add Update"), ListItem::new("Change Status"), ListItem::new("Move to Group"), ListItem::new("Assign User")];
 
+        let list_len = items.len();
         let option_list = List::new(items)
             .block(Block::default().title("Options").borders(Borders::ALL))
             .style(Style::default().fg(Color::White))
             .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
             .highlight_symbol(">>");
 
-        rect.render_stateful_widget(option_list, chunks[1], &mut app.list_state);
+        components::render_list(rect, option_list, chunks[1], list_len, &mut app.list_state, &mut app.mouse);
     }
 
     pub fn keyright(self, app: &mut app::App) {
@@ -667,48 +3411,28 @@ impl ItemOptions {
         app.key_input = vec![]; 
     }
 
-    pub fn keyup(self, app: &mut app::App) {
-        match app.list_state.selected().unwrap() {
-            0 => app.list_state.select(Some(3)),
-            2 => app.list_state.select(Some(1)),
-            3 => app.list_state.select(Some(2)), 
-            _ => app.list_state.select(Some(0)),
-        }
-    }
-
-    pub fn keydown(self, app: &mut app::App) {
-        match app.list_state.selected().unwrap() {
-            0 => app.list_state.select(Some(1)),
-            1 => app.list_state.select(Some(2)),
-            2 => app.list_state.select(Some(3)),
-            _ => app.list_state.select(Some(0)),
-        }
-    }
-
     pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
         match event.code {
             KeyCode::Left => self.keyleft(app),
             KeyCode::Right => self.keyright(app),
-            KeyCode::Up => self.keyup(app),
-            KeyCode::Down => self.keydown(app),
-            KeyCode::Enter => match app.list_state.selected().unwrap() {
-                0 => app.active_menu_item = MenuItem::ItemUpdate,
-                1 => {
+            KeyCode::Enter => match app.list_state.selected() {
+                Some(0) => app.active_menu_item = MenuItem::ItemUpdate,
+                Some(1) => {
                     let board_cache = app.cache.get_board_meta(app.board_detail.id.clone());
                     if board_cache.status_column_id != "".to_string() {
-                        app.status_labels = queries::board_columns(&app.client, app.board_detail.id.clone()); 
+                        app.status_labels = app.api.board_columns(app.board_detail.id.clone()); 
                         app.active_menu_item = MenuItem::StatusOptions; 
                     } else {
                         app.active_menu_item = MenuItem::StatusColumnOptions; 
                     }
                 }, 
-                2 => {
+                Some(2) => {
                     app.active_menu_item = MenuItem::GroupsForMove; 
                 }, 
-                3 => {
+                Some(3) => {
                     let board_cache = app.cache.get_board_meta(app.board_detail.id.clone());
                     if board_cache.user_column_id != "".to_string() {
-                        app.users = queries::user_list(&app); 
+                        app.users = app.api.user_list(app.board_detail.id.clone()); 
                         app.list_state.select(Some(0)); 
                         app.active_menu_item = MenuItem::UserOptions; 
                     } else {
@@ -727,11 +3451,29 @@ impl ItemOptions {
     }
 }
 
+impl<B: Backend> View<B> for ItemOptions {
+    fn title(&self) -> &'static str {
+        "Item Options"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        ItemOptions::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, _app: &app::App) -> Option<usize> {
+        Some(4)
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct ItemUpdate;
 
 impl ItemUpdate {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &mut app::App) {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
         //Default chunks, search, and menu
         let chunks = components::get_default_chunks(&rect);
 
@@ -775,7 +3517,11 @@ impl ItemUpdate {
 
     pub fn keyleft(self, app: &mut app::App) {
         app.key_input = Vec::new();
-        app.active_menu_item = MenuItem::ItemOptions;
+        if app.bulk {
+            app.active_menu_item = MenuItem::BulkOptions;
+        } else {
+            app.active_menu_item = MenuItem::ItemOptions;
+        }
     }
 
     pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
@@ -789,10 +3535,14 @@ impl ItemUpdate {
                     .iter()
                     .map(|x| x.to_string())
                     .collect::<String>();
+                if app.bulk {
+                    start_bulk(app, bulk::BulkAction::AddUpdate(update_text));
+                    return;
+                }
                 // GraphQL create update
-                queries::create_update(&app.client, app.item_detail.id.clone(), update_text);
+                app.api.create_update(app.item_detail.id.clone(), update_text);
                 // Get Item Detail again
-                app.item_detail = queries::item_detail(&app.client, app.item_detail.id.clone());
+                app.fetch_item_detail(app.item_detail.id.clone());
                 //Change menu back to Item Detail
                 app.active_menu_item = MenuItem::ItemDetail;
             }
@@ -801,11 +3551,25 @@ impl ItemUpdate {
     }
 }
 
+impl<B: Backend> View<B> for ItemUpdate {
+    fn title(&self) -> &'static str {
+        "Post Update"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        ItemUpdate::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct UserColumnOptions;
 
 impl UserColumnOptions {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &mut app::App) {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
         //Default chunks, search, and menu
         let chunks = components::get_default_chunks(&rect);
 
@@ -813,13 +3577,14 @@ impl UserColumnOptions {
         let user_columns : Vec<objects::ColumnValue> = app.item_detail.column_values.iter().filter(|cv| cv.type_ == String::from("multiple-person")).cloned().collect::<Vec<objects::ColumnValue>>();
         let items = user_columns.iter().map(|x| ListItem::new(x.title.clone())).collect::<Vec<ListItem>>(); 
 
+        let list_len = items.len();
         let option_list = List::new(items)
             .block(Block::default().title("Select User Column").borders(Borders::ALL))
             .style(Style::default().fg(Color::White))
             .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
             .highlight_symbol(">>");
 
-        rect.render_stateful_widget(option_list, chunks[1], &mut app.list_state);
+        components::render_list(rect, option_list, chunks[1], list_len, &mut app.list_state, &mut app.mouse);
     }
 
     pub fn keyright(self, app: &mut app::App) {
@@ -832,47 +3597,24 @@ impl UserColumnOptions {
         app.key_input = vec![]; 
     }
 
-    pub fn keyup(self, app: &mut app::App) {
-        let user_columns : Vec<objects::ColumnValue> = app.item_detail.column_values.iter().filter(|cv| cv.type_ == String::from("multiple-person")).cloned().collect::<Vec<objects::ColumnValue>>();
-        if let Some(selected) = app.list_state.selected() {
-            if selected == 0 {
-                app.list_state.select(Some(user_columns.len() - 1));
-            } else {
-                app.list_state.select(Some(selected - 1));
-            }
-        }
-    }
-
-    pub fn keydown(self, app: &mut app::App) {
-        let user_columns : Vec<objects::ColumnValue> = app.item_detail.column_values.iter().filter(|cv| cv.type_ == String::from("multiple-person")).cloned().collect::<Vec<objects::ColumnValue>>();
-        if let Some(selected) = app.list_state.selected() {
-            if selected >= user_columns.len() - 1 {
-                app.list_state.select(Some(0));
-            } else {
-                app.list_state.select(Some(selected + 1));
-            }
-        }
-    }
-
     pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
         match event.code {
             KeyCode::Left => self.keyleft(app),
             KeyCode::Right => self.keyright(app),
-            KeyCode::Up => self.keyup(app),
-            KeyCode::Down => self.keydown(app),
             KeyCode::Enter => {
                 let user_columns : Vec<objects::ColumnValue> = app.item_detail.column_values.iter().filter(|cv| cv.type_ == String::from("multiple-person")).cloned().collect::<Vec<objects::ColumnValue>>();
-                let column = user_columns.get(
-                    app.list_state.selected().unwrap()
-                ).unwrap();
+                let column = match app.list_state.selected().and_then(|i| user_columns.get(i)) {
+                    Some(column) => column,
+                    None => return,
+                };
                 let current_board_meta = app.cache.get_board_meta(app.board_detail.id.clone()); 
                 app.cache.update_board_meta(cache::BoardMeta {
                     id : app.item_detail.board.id.clone(), 
-                    status_column_id : current_board_meta.status_column_id.clone(), 
-                    user_column_id : column.id.clone()
+                    user_column_id : column.id.clone(), 
+                    ..current_board_meta
                 }); 
-                cache::write(&app.cache).expect("could not write cache");
-                app.users = queries::user_list(&app); 
+                app.write_cache();
+                app.users = app.api.user_list(app.board_detail.id.clone()); 
                 app.list_state.select(Some(0)); 
                 app.active_menu_item = MenuItem::UserOptions; 
             }, 
@@ -881,11 +3623,29 @@ impl UserColumnOptions {
     }
 }
 
+impl<B: Backend> View<B> for UserColumnOptions {
+    fn title(&self) -> &'static str {
+        "People Columns"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        UserColumnOptions::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(app.item_detail.column_values.iter().filter(|cv| cv.type_ == "multiple-person").count())
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct StatusColumnOptions;
 
 impl StatusColumnOptions {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &mut app::App) {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
         //Default chunks, search, and menu
         let chunks = components::get_default_chunks(&rect);
 
@@ -893,13 +3653,14 @@ impl StatusColumnOptions {
         let status_columns : Vec<objects::ColumnValue> = app.item_detail.column_values.iter().filter(|cv| cv.type_ == String::from("color")).cloned().collect::<Vec<objects::ColumnValue>>();
         let items = status_columns.iter().map(|x| ListItem::new(x.title.clone())).collect::<Vec<ListItem>>(); 
 
+        let list_len = items.len();
         let option_list = List::new(items)
             .block(Block::default().title("Select Status Column").borders(Borders::ALL))
             .style(Style::default().fg(Color::White))
             .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
             .highlight_symbol(">>");
 
-        rect.render_stateful_widget(option_list, chunks[1], &mut app.list_state);
+        components::render_list(rect, option_list, chunks[1], list_len, &mut app.list_state, &mut app.mouse);
     }
 
     pub fn keyright(self, app: &mut app::App) {
@@ -912,47 +3673,24 @@ impl StatusColumnOptions {
         app.key_input = vec![]; 
     }
 
-    pub fn keyup(self, app: &mut app::App) {
-        let status_columns : Vec<objects::ColumnValue> = app.item_detail.column_values.iter().filter(|cv| cv.type_ == String::from("color")).cloned().collect::<Vec<objects::ColumnValue>>();
-        if let Some(selected) = app.list_state.selected() {
-            if selected == 0 {
-                app.list_state.select(Some(status_columns.len() - 1));
-            } else {
-                app.list_state.select(Some(selected - 1));
-            }
-        }
-    }
-
-    pub fn keydown(self, app: &mut app::App) {
-        let status_columns : Vec<objects::ColumnValue> = app.item_detail.column_values.iter().filter(|cv| cv.type_ == String::from("color")).cloned().collect::<Vec<objects::ColumnValue>>();
-        if let Some(selected) = app.list_state.selected() {
-            if selected >= status_columns.len() - 1 {
-                app.list_state.select(Some(0));
-            } else {
-                app.list_state.select(Some(selected + 1));
-            }
-        }
-    }
-
     pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
         match event.code {
             KeyCode::Left => self.keyleft(app),
             KeyCode::Right => self.keyright(app),
-            KeyCode::Up => self.keyup(app),
-            KeyCode::Down => self.keydown(app),
             KeyCode::Enter => {
                 let status_columns : Vec<objects::ColumnValue> = app.item_detail.column_values.iter().filter(|cv| cv.type_ == String::from("color")).cloned().collect::<Vec<objects::ColumnValue>>();
-                let column = status_columns.get(
-                    app.list_state.selected().unwrap()
-                ).unwrap();
+                let column = match app.list_state.selected().and_then(|i| status_columns.get(i)) {
+                    Some(column) => column,
+                    None => return,
+                };
                 let current_board_meta = app.cache.get_board_meta(app.board_detail.id.clone()); 
                 app.cache.update_board_meta(cache::BoardMeta {
                     id : app.item_detail.board.id.clone(), 
                     status_column_id : column.id.clone(), 
-                    user_column_id : current_board_meta.user_column_id.clone()
+                    ..current_board_meta
                 }); 
-                cache::write(&app.cache).expect("could not write cache");
-                app.status_labels = queries::board_columns(&app.client, app.item_detail.board.id.clone()); 
+                app.write_cache();
+                app.status_labels = app.api.board_columns(app.item_detail.board.id.clone()); 
                 app.active_menu_item = MenuItem::StatusOptions; 
             }, 
             _ => {}
@@ -960,21 +3698,40 @@ impl StatusColumnOptions {
     }
 }
 
+impl<B: Backend> View<B> for StatusColumnOptions {
+    fn title(&self) -> &'static str {
+        "Status Columns"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        StatusColumnOptions::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(app.item_detail.column_values.iter().filter(|cv| cv.type_ == "color").count())
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct UserOptions;
 
 impl UserOptions {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &mut app::App) {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
         let chunks = components::get_default_chunks(&rect);
         let items = app.users.iter().map(|x| ListItem::new(x.name.clone().replace("\"", ""))).collect::<Vec<ListItem>>(); 
 
+        let list_len = items.len();
         let option_list = List::new(items)
             .block(Block::default().title("Assign User").borders(Borders::ALL))
             .style(Style::default().fg(Color::White))
             .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
             .highlight_symbol(">>");
 
-        rect.render_stateful_widget(option_list, chunks[1], &mut app.list_state);
+        components::render_list(rect, option_list, chunks[1], list_len, &mut app.list_state, &mut app.mouse);
     }
 
     pub fn keyright(self, app: &mut app::App) {
@@ -987,38 +3744,27 @@ impl UserOptions {
         app.key_input = vec![]; 
     }
 
-    pub fn keyup(self, app: &mut app::App) {
-        if let Some(selected) = app.list_state.selected() {
-            if selected == 0 {
-                app.list_state.select(Some(app.users.len() - 1));
-            } else {
-                app.list_state.select(Some(selected - 1));
-            }
-        }
-    }
-
-    pub fn keydown(self, app: &mut app::App) {
-        if let Some(selected) = app.list_state.selected() {
-            if selected >= app.users.len() - 1 {
-                app.list_state.select(Some(0));
-            } else {
-                app.list_state.select(Some(selected + 1));
-            }
-        }
-    }
-
     pub fn process_input_event(&self, event: KeyEvent, app: &mut app::App) {
         match event.code {
             KeyCode::Left => self.keyleft(app),
             KeyCode::Right => self.keyright(app),
-            KeyCode::Up => self.keyup(app),
-            KeyCode::Down => self.keydown(app),
             KeyCode::Enter => {
-                let selected_user = app.users.get(
-                    app.list_state.selected().unwrap()
-                ).unwrap(); 
-                queries::assign_user(app, selected_user.id.to_string()); 
-                app.item_detail = queries::item_detail(&app.client, app.item_detail.id.clone()); 
+                let selected_user = match app.list_state.selected().and_then(|i| app.users.get(i)) {
+                    Some(user) => user.clone(),
+                    None => return,
+                };
+                if app.bulk {
+                    start_bulk(app, bulk::BulkAction::AssignUser(selected_user.id));
+                    return;
+                }
+                let board_meta = app.cache.get_board_meta(app.item_detail.board.id.clone());
+                app.api.assign_user(
+                    app.board_detail.id.clone(),
+                    app.item_detail.id.clone(),
+                    board_meta.user_column_id,
+                    selected_user.id.to_string(),
+                ); 
+                app.fetch_item_detail(app.item_detail.id.clone()); 
                 app.active_menu_item = MenuItem::ItemDetail; 
             },
             _ => {}
@@ -1026,21 +3772,77 @@ impl UserOptions {
     }
 }
 
+impl<B: Backend> View<B> for UserOptions {
+    fn title(&self) -> &'static str {
+        "Assign User"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        UserOptions::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(app.users.len())
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct StatusOptions;
 
 impl StatusOptions {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &mut app::App) {
+    //Status columns with labels, in board order
+    fn columns(app: &app::App) -> Vec<(String, String)> {
+        let mut columns: Vec<(String, String)> = Vec::new();
+        for label in app.status_labels.iter() {
+            if !columns.iter().any(|(id, _)| *id == label.column_id) {
+                columns.push((label.column_id.clone(), label.column_title.clone()));
+            }
+        }
+        columns
+    }
+
+    //The column being changed: the one picked with Tab, else the board's status column
+    fn active_column(app: &app::App) -> String {
+        let columns = StatusOptions::columns(app);
+        if columns.iter().any(|(id, _)| *id == app.status_column) {
+            return app.status_column.clone();
+        }
+        let board_meta = app.cache.get_board_meta(app.board_detail.id.clone());
+        if columns.iter().any(|(id, _)| *id == board_meta.status_column_id) {
+            return board_meta.status_column_id;
+        }
+        columns.first().map(|(id, _)| id.clone()).unwrap_or(board_meta.status_column_id)
+    }
+
+    fn labels(app: &app::App) -> Vec<objects::Label> {
+        let column = StatusOptions::active_column(app);
+        app.status_labels.iter().filter(|label| label.column_id == column).cloned().collect()
+    }
+
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
         let chunks = components::get_default_chunks(&rect); 
-        let items = app.status_labels.iter().map(|x| ListItem::new(x.name.clone().replace("\"", ""))).collect::<Vec<ListItem>>(); 
+        let items = StatusOptions::labels(app).iter().map(|x| ListItem::new(x.name.clone().replace("\"", ""))).collect::<Vec<ListItem>>(); 
 
+        //Boards with several status columns switch between them with Tab
+        let columns = StatusOptions::columns(app);
+        let active = StatusOptions::active_column(app);
+        let title = match columns.iter().find(|(id, _)| *id == active) {
+            Some((_, column_title)) if columns.len() > 1 => format!("Change {} (Tab: next status column)", column_title),
+            _ => String::from("Change Status"),
+        };
+
+        let list_len = items.len();
         let option_list = List::new(items)
-            .block(Block::default().title("Change Status").borders(Borders::ALL))
+            .block(Block::default().title(title).borders(Borders::ALL))
             .style(Style::default().fg(Color::White))
             .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
             .highlight_symbol(">>");
 
-        rect.render_stateful_widget(option_list, chunks[1], &mut app.list_state);
+        components::render_list(rect, option_list, chunks[1], list_len, &mut app.list_state, &mut app.mouse);
     }
 
     pub fn keyright(self, app: &mut app::App) {
@@ -1053,23 +3855,12 @@ impl StatusOptions {
         app.key_input = vec![]; 
     }
 
-    pub fn keyup(self, app: &mut app::App) {
-        if let Some(selected) = app.list_state.selected() {
-            if selected == 0 {
-                app.list_state.select(Some(app.status_labels.len() - 1));
-            } else {
-                app.list_state.select(Some(selected - 1));
-            }
-        }
-    }
-
-    pub fn keydown(self, app: &mut app::App) {
-        if let Some(selected) = app.list_state.selected() {
-            if selected >= app.status_labels.len() - 1 {
-                app.list_state.select(Some(0));
-            } else {
-                app.list_state.select(Some(selected + 1));
-            }
+    pub fn next_column(self, app: &mut app::App) {
+        let columns = StatusOptions::columns(app);
+        let active = StatusOptions::active_column(app);
+        if let Some(index) = columns.iter().position(|(id, _)| *id == active) {
+            app.status_column = columns[(index + 1) % columns.len()].0.clone();
+            app.list_state.select(Some(0));
         }
     }
 
@@ -1077,14 +3868,25 @@ impl StatusOptions {
         match event.code {
             KeyCode::Left => self.keyleft(app),
             KeyCode::Right => self.keyright(app),
-            KeyCode::Up => self.keyup(app),
-            KeyCode::Down => self.keydown(app),
+            KeyCode::Tab => self.next_column(app),
             KeyCode::Enter => {
-                let selected_label = app.status_labels.get(
-                    app.list_state.selected().unwrap()
-                ).unwrap(); 
-                queries::change_status(app, selected_label.name.clone()); 
-                app.item_detail = queries::item_detail(&app.client, app.item_detail.id.clone()); 
+                let labels = StatusOptions::labels(app);
+                let selected_label = match app.list_state.selected().and_then(|i| labels.get(i)) {
+                    Some(label) => label.clone(),
+                    None => return,
+                };
+                if app.bulk {
+                    app.status_column = selected_label.column_id.clone();
+                    start_bulk(app, bulk::BulkAction::ChangeStatus(selected_label.text()));
+                    return;
+                }
+                app.api.change_status(
+                    app.item_detail.board.id.clone(),
+                    app.item_detail.id.clone(),
+                    selected_label.column_id.clone(),
+                    selected_label.name.clone(),
+                ); 
+                app.fetch_item_detail(app.item_detail.id.clone()); 
                 app.active_menu_item = MenuItem::ItemDetail; 
             },
             _ => {}
@@ -1092,11 +3894,29 @@ impl StatusOptions {
     }
 }
 
+impl<B: Backend> View<B> for StatusOptions {
+    fn title(&self) -> &'static str {
+        "Set Status"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        StatusOptions::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+
+    fn list_len(&self, app: &app::App) -> Option<usize> {
+        Some(StatusOptions::labels(app).len())
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct NewItem;
 
 impl NewItem {
-    pub fn render(rect: &mut Frame<CrosstermBackend<io::Stdout>>, app: &mut app::App) {
+    pub fn render<B: Backend>(rect: &mut Frame<B>, app: &mut app::App) {
         //Default chunks, search, and menu
         let chunks = components::get_default_chunks(&rect);
 
@@ -1155,9 +3975,9 @@ impl NewItem {
                     .map(|x| x.to_string())
                     .collect::<String>();
                 // GraphQL create item
-                let new_item = queries::create_item(item_text, &app);
+                let new_item = app.api.create_item(app.board_detail.id.clone(), app.group_detail.id.clone(), item_text);
                 // Get Item Detail again
-                app.item_detail = queries::item_detail(&app.client, new_item.id.clone());
+                app.fetch_item_detail(new_item.id.clone());
                 //Change menu back to Item Detail
                 app.active_menu_item = MenuItem::ItemDetail;
             }
@@ -1165,3 +3985,17 @@ impl NewItem {
         }
     }
 }
+
+impl<B: Backend> View<B> for NewItem {
+    fn title(&self) -> &'static str {
+        "Create Item"
+    }
+
+    fn render(&self, rect: &mut Frame<B>, app: &mut app::App) {
+        NewItem::render(rect, app)
+    }
+
+    fn handle_event(&self, event: KeyEvent, app: &mut app::App) {
+        self.process_input_event(event, app)
+    }
+}
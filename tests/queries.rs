@@ -0,0 +1,240 @@
+//Every query against recorded API responses in tests/fixtures. To refresh them, run the app with
+//MONDAY_FIXTURES=record:tests/fixtures against a test account and update the expectations here.
+use monday::{api, monday::Client, objects, queries};
+use std::path::PathBuf;
+
+fn client() -> Client {
+    api::HttpApi::replay(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")).client
+}
+
+fn s(text: &str) -> String {
+    text.to_string()
+}
+
+#[test]
+fn board_list() {
+    let boards = queries::board_list(&client());
+    let names = boards.iter().map(|b| b.name.as_str()).collect::<Vec<&str>>();
+    assert_eq!(names, vec!["Roadmap", "Hiring"]);
+    assert_eq!(boards[0].id, "1001");
+}
+
+#[test]
+fn create_board() {
+    let id = queries::create_board(&client(), s("Q3 Roadmap"), objects::BoardKind::Private, Some(12345));
+    assert_eq!(id, "1003");
+}
+
+#[test]
+fn duplicate_board() {
+    let id = queries::duplicate_board(
+        &client(),
+        s("1001"),
+        objects::DuplicateBoardType::Pulses,
+        Some(s("Sprint 14")),
+        None,
+        true,
+    );
+    assert_eq!(id, "1004");
+}
+
+#[test]
+fn archive_board() {
+    queries::archive_board(&client(), s("1002"));
+}
+
+#[test]
+fn add_subscribers() {
+    queries::add_subscribers(&client(), s("1001"), vec![1, 2], true);
+}
+
+#[test]
+fn group_list() {
+    let groups = queries::group_list(&client(), s("1001"));
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].id, "planned");
+    assert_eq!(groups[1].title, "Shipped");
+    assert_eq!(groups[1].color, "#00c875");
+}
+
+#[test]
+fn create_group() {
+    assert_eq!(queries::create_group(&client(), s("1001"), s("Ideas")), "new_group");
+}
+
+#[test]
+fn duplicate_group() {
+    let id = queries::duplicate_group(&client(), s("1001"), s("planned"), s("Planned (copy)"));
+    assert_eq!(id, "planned_copy");
+}
+
+#[test]
+fn archive_group() {
+    queries::archive_group(&client(), s("1001"), s("shipped"));
+}
+
+#[test]
+fn delete_group() {
+    queries::delete_group(&client(), s("1001"), s("shipped"));
+}
+
+#[test]
+fn rename_group() {
+    queries::rename_group(&client(), s("1001"), s("planned"), s("Next Up"));
+}
+
+#[test]
+fn move_group() {
+    queries::move_group(&client(), s("1001"), s("shipped"), s("planned"), true);
+}
+
+#[test]
+fn item_list() {
+    let items = queries::item_list(&client(), s("1001"), s("planned"));
+    assert_eq!(items.len(), 2);
+    let item = &items[0];
+    assert_eq!(item.name, "Fix login redirect");
+    assert_eq!(item.group.id, "planned");
+    assert_eq!(item.creator.id, 1);
+    assert_eq!(item.subscribers[0].id, 1);
+    assert_eq!(item.column_values[0].text, "Working on it");
+    assert_eq!(item.column_values[1].type_, "multiple-person");
+}
+
+#[test]
+fn board_items() {
+    let items = queries::board_items(&client(), s("1001"));
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[2].name, "Release 1.0");
+    assert_eq!(items[2].group.title, "Shipped");
+    //A board that isn't there has no items
+    assert!(queries::board_items(&client(), s("1009")).is_empty());
+}
+
+#[test]
+fn pages_are_fetched_until_one_comes_back_short() {
+    let size = queries::PAGE_SIZE as usize;
+    let mut asked = Vec::new();
+    let all = queries::all_pages(|page| {
+        asked.push(page);
+        let len = if page < 3 { size } else { 7 };
+        Ok::<Vec<i64>, String>(vec![page; len])
+    });
+    assert_eq!(asked, vec![1, 2, 3]);
+    assert_eq!(all.unwrap().len(), size * 2 + 7);
+    //A failed page is the result
+    let failed = queries::all_pages(|page| if page < 2 { Ok(vec![page; size]) } else { Err("timeout") });
+    assert_eq!(failed, Err("timeout"));
+}
+
+#[test]
+fn item_detail() {
+    let item = queries::item_detail(&client(), s("9001"));
+    assert_eq!(item.name, "Fix login redirect");
+    assert_eq!(item.board.name, "Roadmap");
+    assert_eq!(item.group.title, "Planned");
+    assert_eq!(item.subscribers[0].name, "Ada Lovelace");
+    assert_eq!(item.updates.len(), 1);
+    assert_eq!(item.updates[0].text_body, "Reproduced on staging");
+    assert_eq!(item.updates[0].replies[0].creator.name, "Grace Hopper");
+    assert_eq!(item.column_values[0].text, "Working on it");
+}
+
+#[test]
+fn create_update() {
+    assert_eq!(queries::create_update(&client(), s("9001"), s("Fixed in #42")), "7001");
+}
+
+#[test]
+fn board_columns() {
+    let labels = queries::board_columns(&client(), s("1001"));
+    let names = labels.iter().map(|l| l.name.as_str()).collect::<Vec<&str>>();
+    //Label names keep their JSON quotes
+    assert_eq!(names, vec!["\"Working on it\"", "\"Done\"", "\"Stuck\""]);
+    assert!(labels.iter().all(|l| l.column_id == "status" && l.column_title == "Status"));
+}
+
+#[test]
+fn column_schema() {
+    let columns = queries::column_schema(&client(), s("1001"));
+    let ids = columns.iter().map(|c| c.id.as_str()).collect::<Vec<&str>>();
+    assert_eq!(ids, vec!["name", "status", "person"]);
+    assert!(columns[1].is_status());
+    assert!(columns[2].is_people());
+}
+
+#[test]
+fn change_status() {
+    queries::change_status(&client(), s("1001"), s("9001"), s("status"), s("\"Done\""));
+}
+
+#[test]
+fn current_user() {
+    let me = queries::current_user(&client());
+    assert_eq!(me.id, 1);
+    assert_eq!(me.name, "Ada Lovelace");
+    assert_eq!(me.email, "ada@example.com");
+}
+
+#[test]
+fn create_item() {
+    let item = queries::create_item(&client(), s("1001"), s("planned"), s("Export to CSV"));
+    assert_eq!(item.id, "9004");
+}
+
+#[test]
+fn move_item() {
+    queries::move_item(&client(), s("9001"), s("shipped"));
+}
+
+#[test]
+fn user_list() {
+    let users = queries::user_list(&client(), s("1001"));
+    let names = users.iter().map(|u| u.name.as_str()).collect::<Vec<&str>>();
+    assert_eq!(names, vec!["Ada Lovelace", "Grace Hopper"]);
+}
+
+#[test]
+fn assign_user() {
+    queries::assign_user(&client(), s("1001"), s("9001"), s("person"), s("2"));
+}
+
+#[test]
+fn account_users() {
+    let users = queries::account_users(&client());
+    assert_eq!(users.len(), 3);
+    assert_eq!(users[2].email, "linus@example.com");
+}
+
+#[test]
+fn batch_mutation() {
+    let fields = vec![
+        s("archive_item(item_id: 9001) { id }"),
+        s("archive_item(item_id: 1) { id }"),
+    ];
+    let results = queries::batch_mutation(&client(), &fields);
+    assert_eq!(results[0], Ok(()));
+    assert_eq!(results[1], Err(s("Item not found")));
+}
+
+#[test]
+fn search_items_across_boards() {
+    let targets = vec![queries::SearchTarget {
+        board_id: s("1001"),
+        column_ids: vec![s("status")],
+    }];
+    let items = queries::search_items_across_boards(&client(), &targets, "Stuck").unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].name, "Dark mode");
+    assert_eq!(items[0].board.name, "Roadmap");
+}
+
+#[test]
+fn items_across_boards() {
+    let (items, errors) = queries::items_across_boards(&client(), &[s("1001"), s("1009"), s("1002")]);
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1].board.id, "1002");
+    assert_eq!(items[1].group.title, "Applied");
+    //A board that fails loses only its own items
+    assert_eq!(errors, vec!["board 1009: Internal server error"]);
+}
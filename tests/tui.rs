@@ -0,0 +1,825 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use monday::{api, app, cache, events, index, keymap, objects, router, views::MenuItem};
+use serde_json::json;
+use std::collections::HashMap;
+use std::rc::Rc;
+use tempfile::TempDir;
+use tui::{backend::TestBackend, Terminal};
+
+fn board(id: &str, name: &str) -> objects::Board {
+    objects::Board { id: id.to_string(), name: name.to_string() }
+}
+
+fn group(id: &str, title: &str) -> objects::Group {
+    objects::Group { id: id.to_string(), title: title.to_string(), color: String::new() }
+}
+
+fn fixture_account() -> api::FakeAccount {
+    let mut account = api::FakeAccount::new();
+    account.add_user(1, "Ada", "ada@example.com");
+    account.current_user = account.users[0].clone();
+    account.add_board("101", "Roadmap");
+    account.add_board("102", "Hiring");
+    account.add_board("103", "Support");
+    for board_id in &["101", "102", "103"] {
+        account.add_group(board_id, "planned", "Planned");
+        account.add_group(board_id, "shipped", "Shipped");
+        account.add_column(board_id, "status", "Status", "color", &["Working on it", "Stuck", "Done"]);
+        account.add_column(board_id, "person", "Owner", "multiple-person", &[]);
+        account.add_column(board_id, "notes", "Notes", "text", &[]);
+    }
+    for (group, name, status) in &[
+        ("planned", "Fix login redirect", "Working on it"),
+        ("planned", "Dark mode", "Stuck"),
+        ("shipped", "Release 1.0", "Done"),
+    ] {
+        let id = account.add_item("101", group, name);
+        account.set_column(&id, "status", status);
+        account.set_column(&id, "person", "Ada");
+    }
+    account
+}
+
+fn fixture_state(api: &dyn api::MondayApi) -> app::AppState {
+    app::AppState {
+        boards: api.board_list(),
+        board_detail: objects::Board::new(),
+        groups: vec![],
+        group_detail: objects::Group::new(),
+        items: vec![],
+        item_detail: objects::Item::new(),
+        active_menu_item: MenuItem::Home,
+        key_input: vec![],
+        menu_titles: vec!["Home", "Boards", "Groups", "Items", "Item Detail"]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        status_labels: vec![],
+        users: vec![],
+        current_user: api.current_user(),
+        selections: HashMap::new(),
+    }
+}
+
+struct Harness {
+    app: app::App,
+    router: router::Router<TestBackend>,
+    terminal: Terminal<TestBackend>,
+    //The fake behind app.api, for checking what reached the server
+    api: Rc<api::FakeApi>,
+    //Scratch directory the app saves its cache and index to, removed with the harness
+    dir: TempDir,
+}
+
+impl Harness {
+    fn new() -> Harness {
+        Harness::with_account(fixture_account())
+    }
+
+    fn with_account(account: api::FakeAccount) -> Harness {
+        let dir = TempDir::new().expect("could not create test dir");
+        let api = Rc::new(api::FakeApi::new(account));
+        let state = fixture_state(&*api);
+        let cache = cache::Cache { boards: vec![], app_state: state.clone(), recent: vec![] };
+        let path = dir.path().to_string_lossy().to_string();
+        let app = app::App::from_parts(state, cache, api.clone(), index::SearchIndex::default(), path);
+        let terminal = Terminal::new(TestBackend::new(120, 40)).expect("could not create terminal");
+        let mut harness = Harness { app, router: router::Router::new(), terminal, api, dir };
+        harness.draw();
+        harness
+    }
+
+    fn draw(&mut self) {
+        self.app.sync_view();
+        let Harness { app, router, terminal, .. } = self;
+        terminal
+            .draw(|f| {
+                router.render(f, app);
+            })
+            .expect("could not draw");
+    }
+
+    //Sends a key through the same path as the real event loop; returns true if it quit
+    fn key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        self.app.sync_view();
+        let quit = events::handle_input(KeyEvent::new(code, modifiers), &mut self.app, &self.router);
+        self.draw();
+        quit
+    }
+
+    fn press(&mut self, code: KeyCode) -> bool {
+        self.key(code, KeyModifiers::NONE)
+    }
+
+    fn type_str(&mut self, text: &str) {
+        for c in text.chars() {
+            self.press(KeyCode::Char(c));
+        }
+    }
+
+    //Resizes the terminal the way a resize event does: the next frame picks up the new size
+    fn resize(&mut self, width: u16, height: u16) {
+        self.terminal.backend_mut().resize(width, height);
+        self.draw();
+    }
+
+    //Sends a mouse event at a cell, as the real event loop would
+    fn mouse(&mut self, kind: MouseEventKind, column: u16, row: u16) {
+        self.app.sync_view();
+        let event = MouseEvent { kind, column, row, modifiers: KeyModifiers::NONE };
+        events::handle_mouse(event, &mut self.app, &self.router);
+        self.draw();
+    }
+
+    //Left click on the first place the text shows, reading top to bottom
+    fn click(&mut self, text: &str) {
+        let (column, row) = self.find(text);
+        self.mouse(MouseEventKind::Down(MouseButton::Left), column, row);
+    }
+
+    fn find(&self, text: &str) -> (u16, u16) {
+        let screen = self.screen();
+        for (row, line) in screen.lines().enumerate() {
+            if let Some(found) = line.find(text) {
+                return (line[..found].chars().count() as u16, row as u16);
+            }
+        }
+        panic!("expected {:?} on screen:\n{}", text, screen);
+    }
+
+    fn show(&mut self, menu_item: MenuItem) {
+        self.app.active_menu_item = menu_item;
+        self.draw();
+    }
+
+    fn screen(&self) -> String {
+        let buffer = self.terminal.backend().buffer();
+        let width = buffer.area.width as usize;
+        buffer
+            .content
+            .chunks(width)
+            .map(|row| row.iter().map(|cell| cell.symbol.as_str()).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn assert_shows(&self, text: &str) {
+        let screen = self.screen();
+        assert!(screen.contains(text), "expected {:?} on screen:\n{}", text, screen);
+    }
+
+    fn assert_hides(&self, text: &str) {
+        let screen = self.screen();
+        assert!(!screen.contains(text), "did not expect {:?} on screen:\n{}", text, screen);
+    }
+
+    fn selected(&self) -> Option<usize> {
+        self.app.list_state.selected()
+    }
+}
+
+fn menu_items() -> Vec<MenuItem> {
+    MenuItem::names()
+        .iter()
+        .map(|name| serde_json::from_value(json!(name)).expect("unknown view name"))
+        .collect()
+}
+
+#[test]
+fn every_view_renders() {
+    let mut h = Harness::new();
+    h.app.board_detail = board("101", "Roadmap");
+    h.app.groups = vec![group("planned", "Planned"), group("shipped", "Shipped")];
+    h.app.group_detail = group("planned", "Planned");
+    for menu_item in menu_items() {
+        h.show(menu_item);
+        assert_eq!(h.app.active_menu_item, menu_item);
+        assert!(!h.screen().trim().is_empty(), "{:?} rendered nothing", menu_item);
+        //The help bar is cut off on narrow screens, so only its first entry is sure to show
+        if let Some((key, description)) = h.app.help.first().cloned().filter(|_| h.screen().contains("┌Help")) {
+            h.assert_shows(&format!("{}: {}", key, description));
+        }
+    }
+}
+
+#[test]
+fn board_list_shows_and_filters_boards() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.assert_shows("Roadmap");
+    h.assert_shows("Hiring");
+    h.type_str("hir");
+    h.assert_shows("Hiring");
+    h.assert_hides("Roadmap");
+    h.press(KeyCode::Backspace);
+    h.press(KeyCode::Backspace);
+    h.press(KeyCode::Backspace);
+    h.assert_shows("Roadmap");
+}
+
+#[test]
+fn list_selection_wraps() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    assert_eq!(h.selected(), Some(0));
+    h.press(KeyCode::Up);
+    assert_eq!(h.selected(), Some(2));
+    h.press(KeyCode::Down);
+    assert_eq!(h.selected(), Some(0));
+    h.press(KeyCode::Down);
+    assert_eq!(h.selected(), Some(1));
+}
+
+#[test]
+fn empty_lists_do_not_underflow() {
+    let mut h = Harness::new();
+    for menu_item in &[MenuItem::UserOptions, MenuItem::BoardSubscribers, MenuItem::Items, MenuItem::Groups] {
+        h.show(*menu_item);
+        h.press(KeyCode::Up);
+        h.press(KeyCode::Down);
+        h.press(KeyCode::Up);
+        assert_eq!(h.app.active_menu_item, *menu_item);
+    }
+}
+
+#[test]
+fn opens_board_group_and_item() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Groups);
+    h.assert_shows("Planned");
+    h.assert_shows("Shipped");
+
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Items);
+    h.assert_shows("Fix login redirect");
+    h.assert_shows("Dark mode");
+
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::ItemDetail);
+    assert_eq!(h.app.item_detail.name, "Fix login redirect");
+    h.assert_shows("Fix login redirect");
+}
+
+#[test]
+fn history_returns_to_previous_view() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Down);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Groups);
+    assert_eq!(h.app.board_detail.name, "Hiring");
+
+    h.key(KeyCode::Left, KeyModifiers::ALT);
+    assert_eq!(h.app.active_menu_item, MenuItem::Boards);
+    assert_eq!(h.selected(), Some(1));
+
+    h.key(KeyCode::Right, KeyModifiers::ALT);
+    assert_eq!(h.app.active_menu_item, MenuItem::Groups);
+    h.assert_shows("Planned");
+}
+
+#[test]
+fn vim_preset_moves_and_searches() {
+    let mut h = Harness::new();
+    h.app.keymap = keymap::Keymap::from_file(&keymap::KeymapFile {
+        preset: String::from("vim"),
+        global: HashMap::new(),
+        views: HashMap::new(),
+    })
+    .expect("vim preset");
+    h.show(MenuItem::Boards);
+    assert_eq!(h.app.input_mode, app::InputMode::Normal);
+
+    h.press(KeyCode::Char('j'));
+    assert_eq!(h.selected(), Some(1));
+    h.press(KeyCode::Char('k'));
+    assert_eq!(h.selected(), Some(0));
+    h.assert_shows("Roadmap");
+
+    h.press(KeyCode::Char('/'));
+    assert_eq!(h.app.input_mode, app::InputMode::Search);
+    h.type_str("sup");
+    h.assert_shows("Support");
+    h.assert_hides("Roadmap");
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.input_mode, app::InputMode::Normal);
+}
+
+#[test]
+fn custom_bindings_replace_the_help_bar_keys() {
+    let mut h = Harness::new();
+    let file: keymap::KeymapFile =
+        serde_json::from_value(json!({"views": {"Boards": {"create_board": ["Ctrl-n"]}}})).expect("keymap file");
+    h.app.keymap = keymap::Keymap::from_file(&file).expect("custom keymap");
+    h.show(MenuItem::Boards);
+    h.assert_shows("Ctrl-n: Create Board");
+    h.assert_hides("F3: Create Board");
+
+    //The preset key is gone, not kept alongside
+    h.press(KeyCode::F(3));
+    assert_eq!(h.app.active_menu_item, MenuItem::Boards);
+    h.key(KeyCode::Char('n'), KeyModifiers::CONTROL);
+    assert_eq!(h.app.active_menu_item, MenuItem::NewBoard);
+}
+
+#[test]
+fn help_overlay_lists_bindings() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::F(12));
+    assert!(h.app.show_keymap);
+    h.assert_shows("Quit");
+    h.press(KeyCode::Char('x'));
+    assert!(!h.app.show_keymap);
+}
+
+#[test]
+fn quit_key_ends_the_loop() {
+    let mut h = Harness::new();
+    assert!(h.press(KeyCode::Esc));
+}
+
+#[test]
+fn status_change_reaches_the_api() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::ItemDetail);
+    h.assert_shows("Working on it");
+
+    //Item options > Change Status > Stuck
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::ItemOptions);
+    h.press(KeyCode::Down);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::StatusOptions);
+    for _ in 0..3 {
+        if h.screen().contains(">>Stuck") {
+            break;
+        }
+        h.press(KeyCode::Down);
+    }
+    h.press(KeyCode::Enter);
+
+    assert_eq!(h.app.active_menu_item, MenuItem::ItemDetail);
+    let status = h.app.item_detail.column_values.iter().find(|value| value.id == "status").unwrap();
+    assert_eq!(status.text, "Stuck");
+    h.assert_shows("Stuck");
+}
+
+#[test]
+fn clicking_a_menu_tab_opens_its_view() {
+    let mut h = Harness::new();
+    h.click("Boards");
+    assert_eq!(h.app.active_menu_item, MenuItem::Boards);
+    h.assert_shows("Hiring");
+    h.click("Home");
+    assert_eq!(h.app.active_menu_item, MenuItem::Home);
+
+    //Nothing to show until a group has been opened
+    h.click("Items");
+    assert_eq!(h.app.active_menu_item, MenuItem::Home);
+
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    h.click("Home");
+    h.click("Items");
+    assert_eq!(h.app.active_menu_item, MenuItem::Items);
+    h.assert_shows("Fix login redirect");
+    h.key(KeyCode::Left, KeyModifiers::ALT);
+    assert_eq!(h.app.active_menu_item, MenuItem::Home);
+}
+
+#[test]
+fn clicking_a_row_selects_then_opens_it() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    let (column, row) = h.find("Support");
+    h.mouse(MouseEventKind::Down(MouseButton::Left), column, row);
+    assert_eq!(h.selected(), Some(2));
+    assert_eq!(h.app.active_menu_item, MenuItem::Boards);
+    //The board detail now shows the name too, so click the same row again
+    h.mouse(MouseEventKind::Down(MouseButton::Left), column, row);
+    assert_eq!(h.app.active_menu_item, MenuItem::Groups);
+    assert_eq!(h.app.board_detail.name, "Support");
+}
+
+#[test]
+fn scroll_wheel_stops_at_the_ends_of_a_list() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    let (column, row) = h.find("Roadmap");
+    h.mouse(MouseEventKind::ScrollUp, column, row);
+    assert_eq!(h.selected(), Some(0));
+    for _ in 0..5 {
+        h.mouse(MouseEventKind::ScrollDown, column, row);
+    }
+    assert_eq!(h.selected(), Some(2));
+}
+
+#[test]
+fn item_detail_scrolls_its_updates_and_finds_links() {
+    let mut h = Harness::new();
+    let item_id = h.app.api.item_list(String::from("101"), String::from("planned"))[0].id.clone();
+    for n in 0..60 {
+        h.app.api.create_update(item_id.clone(), format!("Update {}", n));
+    }
+    h.app.api.create_update(item_id.clone(), String::from("Spec at https://example.com/spec."));
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::ItemDetail);
+
+    //Newest update first, with its link ready to click
+    h.assert_shows("Ada: Spec at https://example.com/spec.");
+    let (column, row) = h.find("https://example.com/spec");
+    assert_eq!(h.app.mouse.link_at(column, row), Some(String::from("https://example.com/spec")));
+    h.assert_hides("Update 0");
+
+    for _ in 0..100 {
+        h.mouse(MouseEventKind::ScrollDown, column, row);
+    }
+    h.assert_shows("Update 0");
+    h.assert_hides("Spec at");
+    assert!(h.app.mouse.links.is_empty());
+    //Scrolling stops at the last line, so one step up shows one more line at the top
+    h.mouse(MouseEventKind::ScrollUp, column, row);
+    h.assert_hides("Update 0");
+    h.assert_shows("Update 28");
+}
+
+#[test]
+fn clicking_closes_the_help_overlay() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::F(12));
+    h.click("Quit");
+    assert!(!h.app.show_keymap);
+    assert_eq!(h.app.active_menu_item, MenuItem::Boards);
+}
+
+#[test]
+fn every_view_renders_on_small_terminals() {
+    let mut h = Harness::new();
+    h.app.board_detail = board("101", "Roadmap");
+    h.app.groups = vec![group("planned", "Planned"), group("shipped", "Shipped")];
+    h.app.group_detail = group("planned", "Planned");
+    for (width, height) in &[(60, 20), (30, 10), (8, 3), (1, 1)] {
+        h.resize(*width, *height);
+        for menu_item in menu_items() {
+            h.show(menu_item);
+            h.press(KeyCode::F(12));
+            h.press(KeyCode::Char('x'));
+        }
+    }
+}
+
+#[test]
+fn narrow_terminals_drop_side_panes_and_shorten_tabs() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.assert_shows("Detail");
+    h.assert_shows("Item Detail");
+
+    h.resize(40, 20);
+    h.assert_hides("Detail");
+    h.assert_shows("Roadmap");
+    h.assert_shows("Home | Boar | Grou | Item | Item");
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Left);
+    h.click("Grou");
+    assert_eq!(h.app.active_menu_item, MenuItem::Groups);
+
+    h.resize(120, 40);
+    h.show(MenuItem::Boards);
+    h.assert_shows("Detail");
+}
+
+#[test]
+fn new_boards_take_a_name_and_optional_workspace() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::F(3));
+    h.assert_shows("in the main workspace");
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::NewBoard);
+
+    //A workspace that isn't an id keeps the view open
+    h.type_str("Launch");
+    h.press(KeyCode::F(2));
+    h.assert_shows("Workspace Id:");
+    h.assert_shows("\"Launch\"");
+    h.type_str("abc");
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::NewBoard);
+    for _ in 0..3 {
+        h.press(KeyCode::Backspace);
+    }
+    h.type_str("77");
+    h.press(KeyCode::F(2));
+    h.assert_shows("in workspace 77");
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Boards);
+
+    let account = h.api.account.borrow();
+    let board = account.boards.iter().find(|b| b.board.name == "Launch").expect("board was created");
+    assert_eq!(board.workspace_id, Some(77));
+}
+
+#[test]
+fn blank_group_names_are_rejected() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::F(3));
+    h.type_str("   ");
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::NewGroup);
+    assert_eq!(h.app.api.group_list(String::from("101")).len(), 2);
+
+    //Names are trimmed once there's something to keep
+    h.type_str("Backlog ");
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Groups);
+    assert!(h.app.groups.iter().any(|g| g.title == "Backlog"));
+
+    //Rename and duplicate start from the current title
+    let clear = |h: &mut Harness| {
+        for _ in 0.."Backlog".len() {
+            h.press(KeyCode::Backspace);
+        }
+    };
+    h.press(KeyCode::F(4));
+    h.press(KeyCode::Enter);
+    clear(&mut h);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::RenameGroup);
+    h.press(KeyCode::Left);
+    h.press(KeyCode::Down);
+    h.press(KeyCode::Enter);
+    clear(&mut h);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::DuplicateGroup);
+    let groups = h.app.api.group_list(String::from("101"));
+    assert_eq!(groups.len(), 3);
+    assert!(groups.iter().any(|g| g.title == "Backlog"));
+}
+
+#[test]
+fn deleting_a_group_needs_a_second_enter() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::F(4));
+    assert_eq!(h.app.active_menu_item, MenuItem::GroupOptions);
+    for _ in 0..3 {
+        h.press(KeyCode::Down);
+    }
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::GroupOptions);
+    h.assert_shows("Delete Group (Enter again to confirm)");
+    assert_eq!(h.app.api.group_list(String::from("101")).len(), 2);
+
+    //Moving off the option cancels it
+    h.press(KeyCode::Up);
+    h.press(KeyCode::Down);
+    h.assert_hides("Enter again");
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Groups);
+    h.assert_hides("Planned");
+    assert_eq!(h.app.api.group_list(String::from("101")).len(), 1);
+}
+
+#[test]
+fn archiving_a_board_needs_a_second_enter() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::F(4));
+    assert_eq!(h.app.active_menu_item, MenuItem::BoardOptions);
+    for _ in 0..3 {
+        h.press(KeyCode::Down);
+    }
+    h.press(KeyCode::Enter);
+    h.assert_shows("Archive Board (Enter again to confirm)");
+    assert_eq!(h.app.api.board_list().len(), 3);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.api.board_list().len(), 2);
+    assert_eq!(h.app.active_menu_item, MenuItem::Boards);
+}
+
+#[test]
+fn filter_chips_stay_with_their_board_and_saved_views() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Items);
+    h.press(KeyCode::F(2));
+    assert!(h.app.filter.assigned_to_me);
+
+    //Saved with the view
+    h.press(KeyCode::F(10));
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::SaveView);
+    h.type_str("mine");
+    h.press(KeyCode::Enter);
+    let board_meta = h.app.cache.get_board_meta(String::from("101"));
+    assert!(board_meta.get_view("mine").unwrap().chips.assigned_to_me);
+
+    //Cleared on another board
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Down);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.board_detail.name, "Hiring");
+    assert!(!h.app.filter.assigned_to_me);
+
+    //Brought back by applying the view
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Up);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    assert!(!h.app.filter.assigned_to_me);
+    h.press(KeyCode::F(10));
+    h.press(KeyCode::Down);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Items);
+    assert!(h.app.filter.assigned_to_me);
+}
+
+#[test]
+fn space_selects_items_until_a_search_is_typed() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Items);
+
+    //Nothing typed yet, so Space selects and leaves the search box alone
+    h.press(KeyCode::Char(' '));
+    assert_eq!(h.app.selected_items.len(), 1);
+    assert!(h.app.key_input.is_empty());
+
+    //Once a search is typed, Space is part of it
+    h.app.input_mode = app::InputMode::Search;
+    h.type_str("dark mode");
+    assert_eq!(h.app.key_input.iter().collect::<String>(), "dark mode");
+    assert_eq!(h.app.selected_items.len(), 1);
+}
+
+#[test]
+fn table_columns_are_picked_from_the_whole_board() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::F(7));
+    assert_eq!(h.app.active_menu_item, MenuItem::ItemTable);
+    h.assert_hides("Notes");
+
+    //Text columns aren't shown by default, but the picker lists them
+    h.press(KeyCode::F(6));
+    assert_eq!(h.app.active_menu_item, MenuItem::TableColumns);
+    h.assert_shows("[x] Status");
+    h.assert_shows("[ ] Notes");
+    h.press(KeyCode::Down);
+    h.press(KeyCode::Down);
+    h.press(KeyCode::Enter);
+    h.assert_shows("[x] Notes");
+    h.press(KeyCode::Up);
+    h.press(KeyCode::Up);
+    h.press(KeyCode::Enter);
+    h.assert_shows("[ ] Status");
+
+    h.press(KeyCode::Left);
+    assert_eq!(h.app.active_menu_item, MenuItem::ItemTable);
+    h.assert_shows("Notes");
+    let board_meta = h.app.cache.get_board_meta(String::from("101"));
+    assert_eq!(board_meta.visible_columns, vec!["person", "notes"]);
+}
+
+#[test]
+fn the_table_shows_the_listed_items() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    h.type_str("dark");
+    h.press(KeyCode::F(7));
+    h.assert_shows("Dark mode");
+    h.assert_hides("Fix login redirect");
+
+    h.press(KeyCode::F(2));
+    h.assert_shows("Release 1.0");
+    h.press(KeyCode::F(2));
+    h.assert_shows("Dark mode");
+    h.assert_hides("Release 1.0");
+    h.assert_hides("Fix login redirect");
+
+    //Back in the list the search is still there
+    h.press(KeyCode::Left);
+    assert_eq!(h.app.key_input, vec!['d', 'a', 'r', 'k']);
+    h.assert_hides("Fix login redirect");
+}
+
+#[test]
+fn the_index_is_saved_with_the_cache() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Items);
+    //Picking a sort saves the cache
+    h.press(KeyCode::F(8));
+    h.press(KeyCode::Enter);
+    let dir = h.dir.path().to_string_lossy().to_string();
+    assert!(std::path::Path::new(&cache::cache_path(&dir)).exists());
+    let saved = std::fs::read_to_string(index::index_path(&dir)).expect("index was not saved");
+    assert!(saved.contains("Fix login redirect"));
+}
+
+#[test]
+fn enter_on_an_empty_user_list_does_nothing() {
+    let mut h = Harness::new();
+    h.app.users = vec![];
+    h.show(MenuItem::UserOptions);
+    assert_eq!(h.selected(), None);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::UserOptions);
+}
+
+#[test]
+fn enter_on_an_empty_group_does_nothing() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Down);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.board_detail.name, "Hiring");
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Items);
+    assert!(h.app.items.is_empty());
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Items);
+}
+
+#[test]
+fn enter_on_a_search_without_matches_does_nothing() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.type_str("zzz");
+    assert_eq!(h.selected(), None);
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Boards);
+
+    //Same for the groups and items of a board
+    let clear = |h: &mut Harness| {
+        for _ in 0..3 {
+            h.press(KeyCode::Backspace);
+        }
+    };
+    clear(&mut h);
+    h.press(KeyCode::Enter);
+    h.type_str("zzz");
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Groups);
+    clear(&mut h);
+    h.press(KeyCode::Enter);
+    h.type_str("zzz");
+    h.press(KeyCode::Enter);
+    assert_eq!(h.app.active_menu_item, MenuItem::Items);
+}
+
+#[test]
+fn group_chips_reach_across_the_board() {
+    let mut h = Harness::new();
+    h.show(MenuItem::Boards);
+    h.press(KeyCode::Enter);
+    h.press(KeyCode::Enter);
+    h.assert_hides("Release 1.0");
+
+    //Filtering on the open group keeps its items
+    h.press(KeyCode::F(11));
+    assert_eq!(h.app.filter.groups, vec!["planned"]);
+    h.assert_shows("group: Planned");
+    h.assert_shows("Fix login redirect");
+    h.assert_hides("Release 1.0");
+
+    //Adding another group brings in its items
+    h.press(KeyCode::F(4));
+    //After the three fixed filters, three statuses and the Planned group
+    for _ in 0..7 {
+        h.press(KeyCode::Down);
+    }
+    h.press(KeyCode::Enter);
+    h.assert_shows("[x] Group: Shipped");
+    h.press(KeyCode::Left);
+    h.assert_shows("group: Planned, Shipped");
+    h.assert_shows("Release 1.0");
+    h.assert_shows("Fix login redirect");
+}